@@ -0,0 +1,35 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::{StatusEffect, StatusEffects};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Applies a status effect, stacking it with any of the same kind already on the target
+pub struct ApplyStatusEffectAction {
+    pub target: Entity,
+    pub effect: StatusEffect,
+}
+
+impl Action for ApplyStatusEffectAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get_entity(self.target).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if self.can_perform(&mut ImmutableWorld::new(world)) {
+            apply_status_effect(world, self.target, self.effect);
+        }
+        ActionStatus::Finished
+    }
+}
+
+/// Also used directly by actions that apply an effect as part of what they do
+pub fn apply_status_effect(world: &mut World, target: Entity, effect: StatusEffect) {
+    match world.get_mut::<StatusEffects>(target) {
+        Some(mut status_effects) => status_effects.add(effect),
+        None => {
+            let mut status_effects = StatusEffects::default();
+            status_effects.add(effect);
+            world.entity_mut(target).insert(status_effects);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::actions::{Action, ActionStatus, ApplyStatusEffectAction, DamageAction};
+use crate::components::{derived_combat_stats, Equipment, GridPosition, InflictsOnHit};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::prelude::{Entity, World};
+
+/// A melee attack against an adjacent entity
+/// Status effects the attacker or its equipment inflict on hit are applied after the damage
+pub struct AttackAction {
+    pub attacker: Entity,
+    pub target: Entity,
+}
+
+impl Action for AttackAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        match (
+            world.get::<GridPosition>(self.attacker),
+            world.get::<GridPosition>(self.target),
+        ) {
+            (Some(attacker_position), Some(target_position)) => {
+                let offset = **target_position - **attacker_position;
+                offset.x.abs() + offset.y.abs() == 1
+            }
+            _ => false,
+        }
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let attack = derived_combat_stats(world, self.attacker)
+            .map(|stats| stats.attack)
+            .unwrap_or(0);
+        let defense = derived_combat_stats(world, self.target)
+            .map(|stats| stats.defense)
+            .unwrap_or(0);
+        let mut on_hit_effects = world
+            .get::<Equipment>(self.attacker)
+            .map(|equipment| equipment.on_hit_effects())
+            .unwrap_or_default();
+        if let Some(InflictsOnHit(effect)) = world.get::<InflictsOnHit>(self.attacker) {
+            on_hit_effects.push(*effect);
+        }
+        for effect in on_hit_effects {
+            world.add_action(ApplyStatusEffectAction {
+                target: self.target,
+                effect,
+            });
+        }
+        world.add_action(DamageAction {
+            target: self.target,
+            amount: attack.saturating_sub(defense).max(1),
+        });
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,42 @@
+use crate::actions::{Action, ActionStatus, DeathAction, SplitAction};
+use crate::components::{Damageable, Splitting};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::prelude::{Entity, World};
+
+pub struct DamageAction {
+    pub target: Entity,
+    pub amount: u32,
+}
+
+impl Action for DamageAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<Damageable>(self.target).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        // Only the blow that takes the target to zero health kills it
+        let killed = match world.get_mut::<Damageable>(self.target) {
+            Some(mut damageable) => {
+                let was_alive = damageable.health > 0;
+                damageable.health = damageable.health.saturating_sub(self.amount);
+                was_alive && damageable.health == 0
+            }
+            None => return ActionStatus::Finished,
+        };
+
+        if killed {
+            world.add_action(DeathAction {
+                entity: self.target,
+            });
+        } else if world
+            .get::<Splitting>(self.target)
+            .map(|splitting| self.amount >= splitting.damage_threshold)
+            .unwrap_or(false)
+        {
+            world.add_action(SplitAction {
+                entity: self.target,
+            });
+        }
+        ActionStatus::Finished
+    }
+}
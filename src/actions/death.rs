@@ -0,0 +1,66 @@
+use crate::actions::{Action, ActionStatus, ExplodeAction};
+use crate::components::{Actor, Damageable, DropsLoot, Explosive, GridPosition, TurnGroup};
+use crate::items::{spawn_loot, LootSource};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::prelude::{Entity, World};
+use bevy::render::draw::Visible;
+
+/// Removes a killed entity from the world, setting off any explosives and dropping its loot
+/// The player is kept around (the camera is attached to it), but can no longer act,
+/// which the window title reports, see inspect_hovered_tile
+pub struct DeathAction {
+    pub entity: Entity,
+}
+
+impl Action for DeathAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get_entity(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let is_player = match world.get::<Actor>(self.entity) {
+            Some(actor) => actor.turn_group == TurnGroup::Player,
+            None => false,
+        };
+
+        if is_player {
+            let mut player = world.entity_mut(self.entity);
+            player.remove::<Actor>();
+            player.remove::<Damageable>();
+            player.remove::<GridPosition>();
+            if let Some(mut visible) = player.get_mut::<Visible>() {
+                visible.is_visible = false;
+            }
+        } else if world.get_entity(self.entity).is_some() {
+            let explosion = match (
+                world.get::<GridPosition>(self.entity),
+                world.get::<Explosive>(self.entity),
+            ) {
+                (Some(position), Some(explosive)) => Some(ExplodeAction {
+                    position: **position,
+                    radius: explosive.radius,
+                    damage: explosive.damage,
+                }),
+                _ => None,
+            };
+            let loot = match (
+                world.get::<GridPosition>(self.entity),
+                world.get::<DropsLoot>(self.entity),
+            ) {
+                (Some(position), Some(DropsLoot(archetype))) => {
+                    Some((LootSource::Monster(archetype.clone()), **position))
+                }
+                _ => None,
+            };
+
+            world.despawn(self.entity);
+            if let Some((source, position)) = loot {
+                spawn_loot(world, &source, position);
+            }
+            if let Some(explosion) = explosion {
+                world.add_action(explosion);
+            }
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,26 @@
+use crate::actions::{Action, ActionStatus, RegenerateDungeonAction};
+use crate::components::{GridPosition, TileEffect, TileEffectKind};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::prelude::{Entity, World};
+
+/// Takes the stairs the entity is standing on down to a new floor
+pub struct DescendAction {
+    pub entity: Entity,
+}
+
+impl Action for DescendAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let position = match world.get::<GridPosition>(self.entity) {
+            Some(position) => **position,
+            None => return false,
+        };
+        world.query::<&TileEffect>().iter(world).any(|tile_effect| {
+            tile_effect.kind == TileEffectKind::Stairs && tile_effect.position == position
+        })
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        world.add_action(RegenerateDungeonAction::new());
+        ActionStatus::Finished
+    }
+}
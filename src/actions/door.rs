@@ -0,0 +1,96 @@
+use crate::actions::{Action, ActionStatus};
+use crate::bundles::MaterialMap;
+use crate::components::{BlocksSight, Door, FloorItem, GridPosition, Inventory, Terrain};
+use crate::world::ImmutableWorld;
+use bevy::core::Name;
+use bevy::prelude::{Entity, World};
+
+/// Opens a closed door next to the entity, using up a key if it's locked
+pub struct OpenDoorAction {
+    pub entity: Entity,
+    pub door: Entity,
+}
+
+impl Action for OpenDoorAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let (door, position) = match (
+            world.get::<Door>(self.door),
+            world.get::<GridPosition>(self.entity),
+        ) {
+            (Some(door), Some(position)) => (door, **position),
+            _ => return false,
+        };
+        let offset = door.position - position;
+        !door.open
+            && offset.x.abs() + offset.y.abs() == 1
+            && door.can_be_opened_with(world.get::<Inventory>(self.entity))
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let mut door = world.get_mut::<Door>(self.door).unwrap();
+        door.open = true;
+        if let Some(floor) = door.lock.take() {
+            let mut inventory = world.get_mut::<Inventory>(self.entity).unwrap();
+            let index = inventory.key_index(floor).unwrap();
+            inventory.take_one(index);
+        }
+        let mut door = world.entity_mut(self.door);
+        door.remove::<GridPosition>();
+        door.remove::<Terrain>();
+        door.remove::<BlocksSight>();
+        door.insert(Name::new("Open Door"))
+            .insert(MaterialMap::get("door_open"));
+        ActionStatus::Finished
+    }
+}
+
+/// Closes an open door next to the entity, as long as nothing is in the doorway
+pub struct CloseDoorAction {
+    pub entity: Entity,
+    pub door: Entity,
+}
+
+impl Action for CloseDoorAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let mut occupants = world.query::<&GridPosition>();
+        let mut floor_items = world.query::<&FloorItem>();
+        let (door, position) = match (
+            world.get::<Door>(self.door),
+            world.get::<GridPosition>(self.entity),
+        ) {
+            (Some(door), Some(position)) => (door, **position),
+            _ => return false,
+        };
+        let offset = door.position - position;
+        door.open
+            && offset.x.abs() + offset.y.abs() == 1
+            && !occupants
+                .iter(world)
+                .any(|occupant_position| **occupant_position == door.position)
+            && !floor_items
+                .iter(world)
+                .any(|floor_item| floor_item.position == door.position)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let mut door = world.get_mut::<Door>(self.door).unwrap();
+        door.open = false;
+        let position = door.position;
+        world
+            .entity_mut(self.door)
+            .insert(GridPosition::new(position.x, position.y))
+            .insert(Terrain)
+            .insert(BlocksSight)
+            .insert(Name::new("Door"))
+            .insert(MaterialMap::get("door_closed"));
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,38 @@
+use crate::actions::{Action, ActionStatus};
+use crate::bundles::ItemPile;
+use crate::components::{GridPosition, Inventory};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Drops a whole stack from the entity's inventory onto its tile
+pub struct DropAction {
+    pub entity: Entity,
+    pub index: usize,
+}
+
+impl Action for DropAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<GridPosition>(self.entity).is_some()
+            && world
+                .get::<Inventory>(self.entity)
+                .map(|inventory| self.index < inventory.stacks.len())
+                .unwrap_or(false)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let position = **world.get::<GridPosition>(self.entity).unwrap();
+        let stack = world
+            .get_mut::<Inventory>(self.entity)
+            .unwrap()
+            .take(self.index)
+            .unwrap();
+        world
+            .spawn()
+            .insert_bundle(ItemPile::new(stack, position.x, position.y));
+        ActionStatus::Finished
+    }
+}
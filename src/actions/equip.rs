@@ -0,0 +1,83 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::{Equipment, Inventory};
+use crate::items::{EquipmentSlot, ItemKind, ItemStack};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Equips an item from the entity's inventory, putting whatever it replaces in its place
+pub struct EquipAction {
+    pub entity: Entity,
+    pub index: usize,
+}
+
+impl Action for EquipAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let is_equipment = world
+            .get::<Inventory>(self.entity)
+            .and_then(|inventory| inventory.stacks.get(self.index))
+            .map(|stack| matches!(stack.item.kind, ItemKind::Equipment { .. }))
+            .unwrap_or(false);
+        is_equipment && world.get::<Equipment>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let item = world
+            .get_mut::<Inventory>(self.entity)
+            .unwrap()
+            .take_one(self.index)
+            .unwrap();
+
+        let replaced = world.get_mut::<Equipment>(self.entity).unwrap().equip(item);
+        if let Some(replaced) = replaced {
+            // Equipment doesn't stack, so taking it out always leaves room for what it replaces
+            world
+                .get_mut::<Inventory>(self.entity)
+                .unwrap()
+                .add(ItemStack::new(replaced, 1));
+        }
+        ActionStatus::Finished
+    }
+}
+
+/// Takes off whatever is in the slot and puts it in the entity's inventory, if there's room
+pub struct UnequipAction {
+    pub entity: Entity,
+    pub slot: EquipmentSlot,
+}
+
+impl Action for UnequipAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let item = match world
+            .get::<Equipment>(self.entity)
+            .and_then(|equipment| equipment.get(self.slot))
+        {
+            Some(item) => item,
+            None => return false,
+        };
+        world
+            .get::<Inventory>(self.entity)
+            .map(|inventory| inventory.has_room_for(item))
+            .unwrap_or(false)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let item = world
+            .get_mut::<Equipment>(self.entity)
+            .unwrap()
+            .unequip(self.slot)
+            .unwrap();
+        world
+            .get_mut::<Inventory>(self.entity)
+            .unwrap()
+            .add(ItemStack::new(item, 1));
+        ActionStatus::Finished
+    }
+}
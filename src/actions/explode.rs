@@ -0,0 +1,121 @@
+use crate::actions::{
+    Action, ActionStack, ActionStatus, ApplyStatusEffectAction, DamageAction, IgniteAction,
+};
+use crate::bundles::SpriteBundleExt;
+use crate::components::{Damageable, GridPosition, StatusEffect, StatusEffectKind};
+use crate::dungeon::DungeonLayout;
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::math::{IVec2, Rect};
+use bevy::prelude::{Entity, SpriteBundle, With, World};
+use std::time::{Duration, Instant};
+
+/// Damages and stuns everything within the radius that isn't shielded by a wall, and ignites the area
+/// Damage is dealt after the explosion animation, so any chain reactions happen in order
+pub struct ExplodeAction {
+    pub position: IVec2,
+    pub radius: i32,
+    pub damage: u32,
+}
+
+impl Action for ExplodeAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let affected_positions = world
+            .get_resource::<DungeonLayout>()
+            .unwrap()
+            .visible_positions(self.position, self.radius);
+
+        let mut actions = Vec::<Box<dyn Action>>::new();
+        actions.push(Box::new(ExplosionAnimationAction::new(
+            affected_positions.iter().copied().collect(),
+        )));
+        for (_, entity) in world
+            .query_filtered::<(&GridPosition, Entity), With<Damageable>>()
+            .iter(world)
+            .filter(|(position, _)| affected_positions.contains(position))
+        {
+            actions.push(Box::new(DamageAction {
+                target: entity,
+                amount: self.damage,
+            }));
+            actions.push(Box::new(ApplyStatusEffectAction {
+                target: entity,
+                effect: StatusEffect::new(StatusEffectKind::Stun, 1, 0),
+            }));
+        }
+        for position in affected_positions {
+            actions.push(Box::new(IgniteAction { position }));
+        }
+
+        world
+            .get_resource_mut::<ActionStack>()
+            .unwrap()
+            .add_sequence(actions);
+        ActionStatus::Finished
+    }
+}
+
+struct ExplosionAnimationAction {
+    positions: Vec<IVec2>,
+    flashes: Vec<Entity>,
+    started: Option<Instant>,
+    duration: Duration,
+}
+
+impl ExplosionAnimationAction {
+    fn new(positions: Vec<IVec2>) -> Self {
+        Self {
+            positions,
+            flashes: Vec::new(),
+            started: None,
+            duration: Duration::from_millis(150),
+        }
+    }
+}
+
+impl Action for ExplosionAnimationAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let started = match self.started {
+            Some(started) => started,
+            None => {
+                let any_visible = self.positions.iter().any(|position| {
+                    let (x, y) = ((position.x * 32) as f32, (position.y * 32) as f32);
+                    world.is_rect_visible(Rect {
+                        left: x - 16.0,
+                        right: x + 16.0,
+                        top: y + 16.0,
+                        bottom: y - 16.0,
+                    })
+                });
+                if !any_visible {
+                    return ActionStatus::Finished;
+                }
+
+                for position in &self.positions {
+                    let mut sprite = SpriteBundle::new_overlay("explosion", position.x, position.y);
+                    sprite.transform.translation.z = 2.0;
+                    let flash = world.spawn().insert_bundle(sprite).id();
+                    self.flashes.push(flash);
+                }
+                let now = Instant::now();
+                self.started = Some(now);
+                now
+            }
+        };
+
+        if started.elapsed() < self.duration {
+            return ActionStatus::Unfinished;
+        }
+        for flash in self.flashes.drain(..) {
+            world.despawn(flash);
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,63 @@
+use crate::actions::{Action, ActionStack, ActionStatus, DamageAction, Direction, IgniteAction};
+use crate::components::{Damageable, GridPosition, Terrain};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Breathes fire in a straight line, stopping at the first wall or closed door
+/// Damages everything in the line and ignites anything flammable
+pub struct FireBreathAction {
+    pub entity: Entity,
+    pub direction: Direction,
+    pub range: i32,
+    pub damage: u32,
+}
+
+impl Action for FireBreathAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<GridPosition>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let origin = match world.get::<GridPosition>(self.entity) {
+            Some(p) => **p,
+            None => return ActionStatus::Finished,
+        };
+
+        let occupants = world
+            .query::<(&GridPosition, Option<&Damageable>, Option<&Terrain>, Entity)>()
+            .iter(world)
+            .map(|(position, damageable, terrain, entity)| {
+                (**position, damageable.is_some(), terrain.is_some(), entity)
+            })
+            .collect::<Vec<_>>();
+
+        let mut actions = Vec::<Box<dyn Action>>::new();
+        for distance in 1..=self.range {
+            let position = origin + self.direction.offset() * distance;
+            let blocked = occupants.iter().any(|(occupant_position, _, terrain, _)| {
+                *occupant_position == position && *terrain
+            });
+            if blocked {
+                // Scorches the wall it hits, burning away any moss
+                actions.push(Box::new(IgniteAction { position }));
+                break;
+            }
+
+            actions.push(Box::new(IgniteAction { position }));
+            for (occupant_position, damageable, _, entity) in occupants.iter().copied() {
+                if occupant_position == position && damageable {
+                    actions.push(Box::new(DamageAction {
+                        target: entity,
+                        amount: self.damage,
+                    }));
+                }
+            }
+        }
+
+        world
+            .get_resource_mut::<ActionStack>()
+            .unwrap()
+            .add_sequence(actions);
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,38 @@
+use crate::actions::apply_status_effect;
+use crate::actions::{Action, ActionStatus};
+use crate::components::{GridPosition, StatusEffect, StatusEffectKind};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Holds an adjacent target in place for a few turns
+pub struct GrabAction {
+    pub grabber: Entity,
+    pub target: Entity,
+    pub turns: u32,
+}
+
+impl Action for GrabAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        match (
+            world.get::<GridPosition>(self.grabber),
+            world.get::<GridPosition>(self.target),
+        ) {
+            (Some(grabber_position), Some(target_position)) => {
+                let offset = **target_position - **grabber_position;
+                offset.x.abs() + offset.y.abs() == 1
+            }
+            _ => false,
+        }
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if self.can_perform(&mut ImmutableWorld::new(world)) {
+            apply_status_effect(
+                world,
+                self.target,
+                StatusEffect::new(StatusEffectKind::Immobilized, self.turns, 0),
+            );
+        }
+        ActionStatus::Finished
+    }
+}
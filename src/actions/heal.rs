@@ -0,0 +1,23 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::Damageable;
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Restores health, up to the target's maximum
+pub struct HealAction {
+    pub target: Entity,
+    pub amount: u32,
+}
+
+impl Action for HealAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<Damageable>(self.target).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if let Some(mut damageable) = world.get_mut::<Damageable>(self.target) {
+            damageable.health = (damageable.health + self.amount).min(damageable.max_health);
+        }
+        ActionStatus::Finished
+    }
+}
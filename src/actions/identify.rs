@@ -0,0 +1,42 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::Inventory;
+use crate::items::{ItemAppearances, ItemKind};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+use rand::seq::SliceRandom;
+
+/// Identifies one random kind of unidentified consumable the entity is carrying
+pub struct IdentifyAction {
+    pub entity: Entity,
+}
+
+impl Action for IdentifyAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<Inventory>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let appearances = world.get_resource::<ItemAppearances>().unwrap();
+        let unidentified = match world.get::<Inventory>(self.entity) {
+            Some(inventory) => inventory
+                .stacks
+                .iter()
+                .filter_map(|stack| match stack.item.kind {
+                    ItemKind::Consumable(effect) if !appearances.is_identified(effect) => {
+                        Some(effect)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            None => return ActionStatus::Finished,
+        };
+
+        if let Some(effect) = unidentified.choose(&mut rand::thread_rng()) {
+            world
+                .get_resource_mut::<ItemAppearances>()
+                .unwrap()
+                .identify(*effect);
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,72 @@
+use crate::actions::{Action, ActionStatus};
+use crate::bundles::{Fire, MaterialMap};
+use crate::components::{Flammable, GridPosition, TileEffect, TileEffectKind};
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::{Entity, World};
+
+/// Sets everything flammable on a tile alight
+/// A tile that's already burning has its fire refreshed rather than getting a second one
+pub struct IgniteAction {
+    pub position: IVec2,
+}
+
+const BURN_TURNS: u32 = 4;
+
+impl Action for IgniteAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let mut flammables = world
+            .query::<(
+                &Flammable,
+                Option<&GridPosition>,
+                Option<&TileEffect>,
+                Entity,
+            )>()
+            .iter(world)
+            .filter(|(_, grid_position, tile_effect, _)| {
+                grid_position.map(|p| **p) == Some(self.position)
+                    || tile_effect.map(|t| t.position) == Some(self.position)
+            })
+            .map(|(flammable, _, _, entity)| (entity, flammable.burnt_sprite))
+            .collect::<Vec<_>>();
+        if flammables.is_empty() {
+            return ActionStatus::Finished;
+        }
+
+        for (entity, burnt_sprite) in flammables.drain(..) {
+            match burnt_sprite {
+                Some(burnt_sprite) => {
+                    let mut entity = world.entity_mut(entity);
+                    entity.remove::<Flammable>();
+                    entity.insert(MaterialMap::get(burnt_sprite));
+                }
+                None => {
+                    world.despawn(entity);
+                }
+            }
+        }
+        let existing_fire = world
+            .query::<&mut TileEffect>()
+            .iter_mut(world)
+            .find(|tile_effect| {
+                tile_effect.position == self.position && tile_effect.kind == TileEffectKind::Fire
+            });
+        match existing_fire {
+            Some(mut fire) => {
+                fire.turns_remaining = fire.turns_remaining.max(Some(BURN_TURNS));
+            }
+            None => {
+                world.spawn().insert_bundle(Fire::new(
+                    self.position.x,
+                    self.position.y,
+                    BURN_TURNS,
+                ));
+            }
+        }
+        ActionStatus::Finished
+    }
+}
@@ -1,9 +1,61 @@
 mod action;
+mod apply_status_effect;
+mod attack;
+mod damage;
+mod death;
+mod descend;
+mod door;
+mod drop;
+mod equip;
+mod explode;
+mod fire_breath;
+mod grab;
+mod heal;
+mod identify;
+mod ignite;
 mod moove;
-mod print_entity;
+mod pick_up;
+mod populate_dungeon;
+mod raise_alarm;
 mod regenerate_dungeon;
+mod reveal;
+mod reveal_map;
+mod shoot;
+mod spin_web;
+mod split;
+mod struggle;
+mod teleport;
+mod throw;
+mod use_item;
+mod wait;
 
 pub use action::*;
+pub use apply_status_effect::*;
+pub use attack::*;
+pub use damage::*;
+pub use death::*;
+pub use descend::*;
+pub use door::*;
+pub use drop::*;
+pub use equip::*;
+pub use explode::*;
+pub use fire_breath::*;
+pub use grab::*;
+pub use heal::*;
+pub use identify::*;
+pub use ignite::*;
 pub use moove::*;
-pub use print_entity::*;
+pub use pick_up::*;
+pub use populate_dungeon::*;
+pub use raise_alarm::*;
 pub use regenerate_dungeon::*;
+pub use reveal::*;
+pub use reveal_map::*;
+pub use shoot::*;
+pub use spin_web::*;
+pub use split::*;
+pub use struggle::*;
+pub use teleport::*;
+pub use throw::*;
+pub use use_item::*;
+pub use wait::*;
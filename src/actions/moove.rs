@@ -1,11 +1,21 @@
-use crate::actions::{Action, ActionStatus};
-use crate::components::GridPosition;
+use crate::actions::{apply_status_effect, is_webbed, Action, ActionStatus};
+use crate::bundles::ToxicPuddle;
+use crate::components::{
+    has_status_effect, GridPosition, Phasing, StatusEffect, StatusEffectKind, Terrain, TileEffect,
+    TileEffectKind, ToxicTrail, WebWalker,
+};
+use crate::dungeon::DungeonLayout;
 use crate::world::{ImmutableWorld, WorldExt};
 use bevy::core::Time;
-use bevy::math::Rect;
+use bevy::math::{IVec2, Rect};
 use bevy::prelude::{Entity, GlobalTransform, Transform, World};
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+const WEB_STUCK_TURNS: u32 = 4;
+
+/// Moves an entity one tile, getting it stuck if it walks into a web
+/// Entities with a toxic trail leave a puddle behind
 pub struct MoveAction {
     pub entity: Entity,
     pub direction: Direction,
@@ -13,54 +23,110 @@ pub struct MoveAction {
 
 impl Action for MoveAction {
     fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        if has_status_effect(world, self.entity, StatusEffectKind::Immobilized) {
+            return false;
+        }
+
         let current_position = match world.get::<GridPosition>(self.entity) {
-            Some(p) => p,
+            Some(p) => **p,
             None => return false,
         };
 
-        let mut intended_position = current_position.clone();
-        match self.direction {
-            Direction::Up => intended_position.y += 1,
-            Direction::Down => intended_position.y -= 1,
-            Direction::Left => intended_position.x -= 1,
-            Direction::Right => intended_position.x += 1,
+        can_enter(
+            self.entity,
+            current_position,
+            current_position + self.direction.offset(),
+            world,
+        )
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
         }
 
-        for position in world.query::<&GridPosition>().iter(&world) {
-            if position == &intended_position {
-                return false;
-            }
+        let old_position = **world.get::<GridPosition>(self.entity).unwrap();
+        let new_position = old_position + self.direction.offset();
+        **world.get_mut::<GridPosition>(self.entity).unwrap() = new_position;
+        if let Some(puddle_turns) = world
+            .get::<ToxicTrail>(self.entity)
+            .map(|toxic_trail| toxic_trail.puddle_turns)
+        {
+            leave_toxic_puddle(world, old_position, puddle_turns);
         }
-        true
+        if world.get::<WebWalker>(self.entity).is_none()
+            && is_webbed(new_position, &mut ImmutableWorld::new(world))
+        {
+            apply_status_effect(
+                world,
+                self.entity,
+                StatusEffect::new(StatusEffectKind::Immobilized, WEB_STUCK_TURNS, 0),
+            );
+        }
+        world.add_action(MoveAnimationAction::new(self.entity, self.direction));
+        ActionStatus::Finished
     }
+}
 
-    fn perform(&mut self, world: &mut World) -> ActionStatus {
-        let current_position = match world.get::<GridPosition>(self.entity) {
-            Some(p) => p,
-            None => return ActionStatus::Finished,
-        };
-
-        let mut intended_position = current_position.clone();
-        match self.direction {
-            Direction::Up => intended_position.y += 1,
-            Direction::Down => intended_position.y -= 1,
-            Direction::Left => intended_position.x -= 1,
-            Direction::Right => intended_position.x += 1,
+/// Refreshes the puddle already on the tile rather than stacking another one on it
+fn leave_toxic_puddle(world: &mut World, position: IVec2, turns: u32) {
+    let existing_puddle = world
+        .query::<&mut TileEffect>()
+        .iter_mut(world)
+        .find(|tile_effect| {
+            tile_effect.position == position && tile_effect.kind == TileEffectKind::Toxic
+        });
+    match existing_puddle {
+        Some(mut puddle) => {
+            puddle.turns_remaining = puddle.turns_remaining.max(Some(turns));
+        }
+        None => {
+            world
+                .spawn()
+                .insert_bundle(ToxicPuddle::new(position.x, position.y, turns));
         }
+    }
+}
 
-        for position in world.query::<&GridPosition>().iter(&world) {
-            if position == &intended_position {
-                return ActionStatus::Finished;
+/// Whether an entity can step from one tile onto an adjacent one
+/// Floor tiles can be entered if nothing else is on them
+/// Terrain tiles can only be entered by phasing entities that aren't already inside terrain
+pub fn can_enter(
+    entity: Entity,
+    current_position: IVec2,
+    intended_position: IVec2,
+    world: &mut ImmutableWorld,
+) -> bool {
+    let phasing = world.get::<Phasing>(entity).is_some();
+    let mut inside_terrain = false;
+    let mut intended_terrain = false;
+    for (position, terrain) in world
+        .query::<(&GridPosition, Option<&Terrain>)>()
+        .iter(world)
+    {
+        if **position == current_position && terrain.is_some() {
+            inside_terrain = true;
+        }
+        if **position == intended_position {
+            if terrain.is_some() {
+                intended_terrain = true;
+            } else {
+                return false;
             }
         }
+    }
 
-        *world.get_mut::<GridPosition>(self.entity).unwrap() = intended_position;
-        world.add_action(MoveAnimationAction::new(self.entity, self.direction));
-        ActionStatus::Finished
+    if intended_terrain {
+        phasing && !inside_terrain
+    } else {
+        world
+            .get_resource::<DungeonLayout>()
+            .unwrap()
+            .is_floor(intended_position)
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum Direction {
     Up,
     Down,
@@ -68,6 +134,31 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn offset(self) -> IVec2 {
+        match self {
+            Direction::Up => IVec2::new(0, 1),
+            Direction::Down => IVec2::new(0, -1),
+            Direction::Left => IVec2::new(-1, 0),
+            Direction::Right => IVec2::new(1, 0),
+        }
+    }
+
+    pub fn from_offset(offset: IVec2) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|direction| direction.offset() == offset)
+    }
+}
+
 struct MoveAnimationAction {
     entity: Entity,
     direction: Direction,
@@ -0,0 +1,68 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::{FloorItem, GridPosition, Inventory};
+use crate::items::{Item, ItemCategory};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// Picks up as much as fits of every item lying on the entity's tile
+pub struct PickUpAction {
+    pub entity: Entity,
+    /// Only items of these categories are picked up, every item if None
+    pub categories: Option<Vec<ItemCategory>>,
+}
+
+impl PickUpAction {
+    fn wants(&self, item: &Item) -> bool {
+        self.categories
+            .as_ref()
+            .map(|categories| categories.contains(&item.kind.category()))
+            .unwrap_or(true)
+    }
+}
+
+impl Action for PickUpAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let position = match world.get::<GridPosition>(self.entity) {
+            Some(position) => **position,
+            None => return false,
+        };
+        let mut floor_items = world.query::<&FloorItem>();
+        let inventory = match world.get::<Inventory>(self.entity) {
+            Some(inventory) => inventory,
+            None => return false,
+        };
+        floor_items.iter(world).any(|floor_item| {
+            floor_item.position == position
+                && self.wants(&floor_item.stack.item)
+                && inventory.has_room_for(&floor_item.stack.item)
+        })
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let position = **world.get::<GridPosition>(self.entity).unwrap();
+        let floor_items = world
+            .query::<(&FloorItem, Entity)>()
+            .iter(world)
+            .filter(|(floor_item, _)| {
+                floor_item.position == position && self.wants(&floor_item.stack.item)
+            })
+            .map(|(floor_item, entity)| (floor_item.stack.clone(), entity))
+            .collect::<Vec<_>>();
+        for (stack, floor_item_entity) in floor_items {
+            let leftover = world.get_mut::<Inventory>(self.entity).unwrap().add(stack);
+            match leftover {
+                Some(leftover) => {
+                    world.get_mut::<FloorItem>(floor_item_entity).unwrap().stack = leftover;
+                }
+                None => {
+                    world.despawn(floor_item_entity);
+                }
+            }
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,125 @@
+use crate::actions::{Action, ActionStatus, Room, RoomKind};
+use crate::components::GridPosition;
+use crate::dungeon::{DungeonDepth, DungeonLayout};
+use crate::items::{spawn_loot_with_rng, LootLibrary, LootSource};
+use crate::monsters::{spawn_monster, MonsterDefinition, MonsterLibrary, SpawnTables};
+use crate::world::ImmutableWorld;
+use bevy::asset::{AssetServer, Assets};
+use bevy::log::warn;
+use bevy::math::IVec2;
+use bevy::prelude::World;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+type PlannedPopulation = (Vec<(MonsterDefinition, IVec2)>, Vec<(RoomKind, IVec2)>);
+
+/// Fills every room but the first with groups of monsters rolled from the spawn tables,
+/// and leaves whatever the loot table for the kind of room rolls lying in it
+/// Waits for the monster definitions and spawn and loot tables to finish loading first
+/// Everything is rolled from where the floor's RNG was left after generating it
+pub struct PopulateDungeonAction {
+    pub rooms: Vec<Room>,
+    pub rng: StdRng,
+}
+
+impl Action for PopulateDungeonAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let asset_server = world.get_resource::<AssetServer>().unwrap();
+        let monsters_ready = world
+            .get_resource::<MonsterLibrary>()
+            .unwrap()
+            .is_ready(asset_server);
+        let loot_ready = world
+            .get_resource::<LootLibrary>()
+            .unwrap()
+            .is_ready(asset_server);
+        if !monsters_ready || !loot_ready {
+            return ActionStatus::Unfinished;
+        }
+
+        let (spawns, loot_positions) = match self.plan(world) {
+            Some(planned) => planned,
+            None => return ActionStatus::Finished,
+        };
+
+        for (definition, position) in spawns {
+            spawn_monster(world, &definition, position);
+        }
+        for (kind, position) in loot_positions {
+            spawn_loot_with_rng(world, &LootSource::Room(kind), position, &mut self.rng);
+        }
+        ActionStatus::Finished
+    }
+}
+
+impl PopulateDungeonAction {
+    /// Picks which monsters go where and where each room's loot is left
+    /// Returns None if the spawn tables failed to load
+    fn plan(&mut self, world: &mut World) -> Option<PlannedPopulation> {
+        let mut occupied_positions = world
+            .query::<&GridPosition>()
+            .iter(world)
+            .map(|position| **position)
+            .collect::<HashSet<_>>();
+        let depth = world.get_resource::<DungeonDepth>().unwrap().0;
+        let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+        let monster_library = world.get_resource::<MonsterLibrary>().unwrap();
+        let definitions = world.get_resource::<Assets<MonsterDefinition>>().unwrap();
+        let spawn_tables = match monster_library
+            .spawn_tables(world.get_resource::<Assets<SpawnTables>>().unwrap())
+        {
+            Some(spawn_tables) => spawn_tables,
+            None => {
+                warn!("Failed to load spawn tables, the floor will be empty");
+                return None;
+            }
+        };
+        let can_appear = |name: &str, depth: u32| {
+            monster_library
+                .get(name, definitions)
+                .map(|definition| {
+                    (definition.depth_range.0..=definition.depth_range.1).contains(&depth)
+                })
+                .unwrap_or(false)
+        };
+
+        let rng = &mut self.rng;
+        let mut spawns = Vec::new();
+        let mut loot_positions = Vec::new();
+        for room in self.rooms.iter().skip(1) {
+            let mut free_positions = room
+                .floor_positions()
+                .filter(|position| {
+                    dungeon_layout.is_floor(*position) && !occupied_positions.contains(position)
+                })
+                .collect::<Vec<IVec2>>();
+            free_positions.shuffle(rng);
+
+            let (fewest_groups, most_groups) = spawn_tables.groups_per_room;
+            for _ in 0..rng.gen_range(fewest_groups..=most_groups.max(fewest_groups)) {
+                let (name, group_size) = match spawn_tables.roll_group(depth, can_appear, rng) {
+                    Some(group) => group,
+                    None => continue,
+                };
+                let definition = monster_library.get(&name, definitions).unwrap();
+                for _ in 0..group_size {
+                    if let Some(position) = free_positions.pop() {
+                        occupied_positions.insert(position);
+                        spawns.push((definition.clone(), position));
+                    }
+                }
+            }
+
+            if let Some(position) = free_positions.pop() {
+                loot_positions.push((room.kind, position));
+            }
+        }
+        Some((spawns, loot_positions))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::{Actor, GridPosition, TurnGroup};
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::{Entity, World};
+
+/// Tells every other enemy within the radius where the player was seen
+pub struct RaiseAlarmAction {
+    pub entity: Entity,
+    pub player_position: IVec2,
+    pub radius: i32,
+}
+
+impl Action for RaiseAlarmAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<GridPosition>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let alarm_position = match world.get::<GridPosition>(self.entity) {
+            Some(p) => **p,
+            None => return ActionStatus::Finished,
+        };
+
+        for (mut actor, position, entity) in world
+            .query::<(&mut Actor, &GridPosition, Entity)>()
+            .iter_mut(world)
+        {
+            let offset = **position - alarm_position;
+            if entity != self.entity
+                && actor.turn_group == TurnGroup::Enemy
+                && offset.x * offset.x + offset.y * offset.y <= self.radius * self.radius
+            {
+                actor.brain.hear_alarm(self.player_position);
+            }
+        }
+        ActionStatus::Finished
+    }
+}
@@ -1,12 +1,22 @@
-use crate::actions::{Action, ActionStatus};
-use crate::bundles::{Floor, Wall};
-use crate::components::KeepBetweenFloors;
-use crate::world::ImmutableWorld;
+use crate::actions::{Action, ActionStatus, PopulateDungeonAction};
+use crate::bundles::{ClosedDoor, Floor, ItemPile, Stairs, Wall};
+use crate::components::{Flammable, GridPosition, KeepBetweenFloors};
+use crate::dungeon::{DungeonDepth, DungeonLayout, RunSeed};
+use crate::items::{key, ItemStack};
+use crate::world::{ImmutableWorld, WorldExt};
 use bevy::math::IVec2;
-use bevy::prelude::{Entity, Without, World};
+use bevy::prelude::{Entity, Transform, Without, World};
+use rand::seq::SliceRandom;
 use rand::Rng;
+use serde::Deserialize;
 use std::collections::HashSet;
 
+const DOOR_CHANCE: f64 = 0.6;
+const LOCKED_DOOR_CHANCE: f64 = 0.4;
+
+/// Replaces the current floor with a newly generated one, one floor deeper
+/// The player is moved to the starting room, and the other rooms are populated afterwards
+/// The layout is rolled from the floor's own RNG, see RunSeed::floor_rng
 pub struct RegenerateDungeonAction {
     rooms: Vec<Room>,
     wall_positions: HashSet<IVec2>,
@@ -29,11 +39,22 @@ impl Action for RegenerateDungeonAction {
     }
 
     fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let depth = world.get_resource::<DungeonDepth>().unwrap().0 + 1;
+        let mut rng = world.get_resource::<RunSeed>().unwrap().floor_rng(depth);
         Self::cleanup_previous(world);
-        self.plan_rooms();
-        self.plan_corridors();
-        self.create_walls(world);
+        self.plan_rooms(&mut rng);
+        self.plan_corridors(&mut rng);
+        self.create_walls(world, &mut rng);
         self.create_floors(world);
+        self.save_layout(world);
+        self.place_stairs(world, &mut rng);
+        self.move_player_to_start(world);
+        world.get_resource_mut::<DungeonDepth>().unwrap().0 = depth;
+        self.place_doors(world, &mut rng);
+        world.add_action(PopulateDungeonAction {
+            rooms: std::mem::take(&mut self.rooms),
+            rng,
+        });
 
         ActionStatus::Finished
     }
@@ -50,18 +71,19 @@ impl RegenerateDungeonAction {
         }
     }
 
-    fn plan_rooms(&mut self) {
+    fn plan_rooms<R: Rng>(&mut self, rng: &mut R) {
         let starting_room = Room {
             center: IVec2::new(0, 0),
             radius: IVec2::new(3, 3),
+            kind: RoomKind::Start,
         };
         self.rooms.push(starting_room);
 
-        let mut rng = rand::thread_rng();
         'room_placing_loop: for _ in 0..200 {
             let room = Room {
                 center: IVec2::new(rng.gen_range(-30..31), rng.gen_range(-30..31)),
                 radius: IVec2::new(rng.gen_range(2..8), rng.gen_range(2..8)),
+                kind: RoomKind::roll(rng),
             };
             for other_room in &self.rooms {
                 let required_gap = rng.gen_range(3..10);
@@ -82,8 +104,7 @@ impl RegenerateDungeonAction {
         }
     }
 
-    fn plan_corridors(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn plan_corridors<R: Rng>(&mut self, rng: &mut R) {
         for (start_room_index, start_room) in self.rooms.iter().enumerate() {
             let mut end_room_index = rng.gen_range(0..self.rooms.len());
             while end_room_index == start_room_index {
@@ -117,7 +138,7 @@ impl RegenerateDungeonAction {
         }
     }
 
-    fn create_walls(&mut self, world: &mut World) {
+    fn create_walls<R: Rng>(&mut self, world: &mut World, rng: &mut R) {
         for room in &self.rooms {
             for x in -(room.radius.x + 1)..=(room.radius.x + 1) {
                 self.wall_positions.insert(IVec2::new(
@@ -157,21 +178,26 @@ impl RegenerateDungeonAction {
             }
         }
 
-        for position in self.wall_positions.difference(&self.floor_positions) {
-            world
-                .spawn()
-                .insert_bundle(Wall::new(position.x, position.y));
+        // Moss burns away, leaving a plain wall
+        // Walls are rolled in a fixed order, sets don't iterate the same way twice
+        let mut wall_positions = self
+            .wall_positions
+            .difference(&self.floor_positions)
+            .collect::<Vec<_>>();
+        wall_positions.sort_by_key(|position| (position.x, position.y));
+        for position in wall_positions {
+            let mossy = rng.gen_ratio(1, 4);
+            let mut wall = world.spawn();
+            wall.insert_bundle(Wall::new(position.x, position.y, mossy));
+            if mossy {
+                wall.insert(Flammable::new(Some("wall.png")));
+            }
         }
     }
 
     fn create_floors(&mut self, world: &mut World) {
         for room in &self.rooms {
-            for x in -room.radius.x..=room.radius.x {
-                for y in -room.radius.y..=room.radius.y {
-                    self.floor_positions
-                        .insert(IVec2::new(room.center.x + x, room.center.y + y));
-                }
-            }
+            self.floor_positions.extend(room.floor_positions());
         }
 
         for position in &self.floor_positions {
@@ -180,11 +206,166 @@ impl RegenerateDungeonAction {
                 .insert_bundle(Floor::new(position.x, position.y));
         }
     }
+
+    fn save_layout(&self, world: &mut World) {
+        world.insert_resource(DungeonLayout::new(
+            self.floor_positions.clone(),
+            self.wall_positions
+                .difference(&self.floor_positions)
+                .copied()
+                .collect(),
+            self.rooms.iter().map(|room| room.center).collect(),
+        ));
+    }
+
+    fn place_stairs<R: Rng>(&self, world: &mut World, rng: &mut R) {
+        if let Some(room) = self.rooms[1..].choose(rng) {
+            world
+                .spawn()
+                .insert_bundle(Stairs::new(room.center.x, room.center.y));
+        }
+    }
+
+    /// Puts doors in some of the gaps corridors leave in room walls, and sometimes locks one
+    /// The key to a locked door is left in a room that can be reached without going through it
+    fn place_doors<R: Rng>(&self, world: &mut World, rng: &mut R) {
+        // Rooms sharing a wall share its entrances, so each gap is only rolled for once
+        let mut entrances = self
+            .rooms
+            .iter()
+            .flat_map(|room| room.entrances(&self.floor_positions))
+            .collect::<Vec<_>>();
+        entrances.sort_by_key(|position| (position.x, position.y));
+        entrances.dedup();
+        let mut door_positions = entrances
+            .into_iter()
+            .filter(|_| rng.gen_bool(DOOR_CHANCE))
+            .collect::<Vec<_>>();
+        door_positions.shuffle(rng);
+
+        let floor = world.get_resource::<DungeonDepth>().unwrap().0;
+        let start = self.rooms[0].center;
+        let mut locked = None;
+        if rng.gen_bool(LOCKED_DOOR_CHANCE) {
+            if let Some(door_position) = door_positions.first().copied() {
+                let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+                let mut reachable_rooms = self
+                    .rooms
+                    .iter()
+                    .skip(1)
+                    .filter(|room| {
+                        dungeon_layout
+                            .find_path(start, room.center, |position| position != door_position)
+                            .is_some()
+                    })
+                    .collect::<Vec<_>>();
+                reachable_rooms.shuffle(rng);
+                let key_position = reachable_rooms.first().and_then(|room| {
+                    room.floor_positions()
+                        .filter(|position| *position != room.center)
+                        .collect::<Vec<_>>()
+                        .choose(rng)
+                        .copied()
+                });
+                if let Some(key_position) = key_position {
+                    locked = Some(door_position);
+                    world.spawn().insert_bundle(ItemPile::new(
+                        ItemStack::new(key(floor), 1),
+                        key_position.x,
+                        key_position.y,
+                    ));
+                }
+            }
+        }
+
+        for position in door_positions {
+            let lock = if locked == Some(position) {
+                Some(floor)
+            } else {
+                None
+            };
+            world
+                .spawn()
+                .insert_bundle(ClosedDoor::new(position.x, position.y, lock));
+        }
+    }
+
+    fn move_player_to_start(&self, world: &mut World) {
+        let player = match ImmutableWorld::new(world).player_entity() {
+            Some(player) => player,
+            None => return,
+        };
+        let start = self.rooms[0].center;
+        if let Some(mut position) = world.get_mut::<GridPosition>(player) {
+            **position = start;
+        }
+        if let Some(mut transform) = world.get_mut::<Transform>(player) {
+            transform.translation.x = (start.x * 32) as f32;
+            transform.translation.y = (start.y * 32) as f32;
+        }
+    }
 }
 
-struct Room {
-    center: IVec2,
-    radius: IVec2,
+pub struct Room {
+    pub center: IVec2,
+    pub radius: IVec2,
+    pub kind: RoomKind,
+}
+
+/// What a room was used for, deciding which loot table its floor loot is rolled from
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+pub enum RoomKind {
+    /// Where the player arrives, which is left empty
+    Start,
+    Ordinary,
+    Storeroom,
+    Armory,
+    Library,
+}
+
+impl RoomKind {
+    fn roll<R: Rng>(rng: &mut R) -> Self {
+        match rng.gen_range(0..10) {
+            0 => RoomKind::Storeroom,
+            1 => RoomKind::Armory,
+            2 => RoomKind::Library,
+            _ => RoomKind::Ordinary,
+        }
+    }
+}
+
+impl Room {
+    pub fn floor_positions(&self) -> impl Iterator<Item = IVec2> + '_ {
+        (-self.radius.x..=self.radius.x).flat_map(move |x| {
+            (-self.radius.y..=self.radius.y).map(move |y| self.center + IVec2::new(x, y))
+        })
+    }
+
+    /// Gaps in the room's walls one tile wide, with floor on either side and wall at both ends
+    fn entrances<'a>(
+        &'a self,
+        floor_positions: &'a HashSet<IVec2>,
+    ) -> impl Iterator<Item = IVec2> + 'a {
+        let horizontal_walls = (-self.radius.x..=self.radius.x).flat_map(move |x| {
+            [self.radius.y + 1, -self.radius.y - 1]
+                .map(|y| (self.center + IVec2::new(x, y), IVec2::X))
+        });
+        let vertical_walls = (-self.radius.y..=self.radius.y).flat_map(move |y| {
+            [self.radius.x + 1, -self.radius.x - 1]
+                .map(|x| (self.center + IVec2::new(x, y), IVec2::Y))
+        });
+        horizontal_walls
+            .chain(vertical_walls)
+            .filter(move |(position, along_wall)| {
+                let across_wall = along_wall.perp();
+                floor_positions.contains(position)
+                    && !floor_positions.contains(&(*position + *along_wall))
+                    && !floor_positions.contains(&(*position - *along_wall))
+                    && floor_positions.contains(&(*position + across_wall))
+                    && floor_positions.contains(&(*position - across_wall))
+            })
+            .map(|(position, _)| position)
+    }
 }
 
 fn neighbors(p: &IVec2) -> [IVec2; 8] {
@@ -0,0 +1,43 @@
+use crate::actions::{Action, ActionStatus, GrabAction};
+use crate::components::{Disguise, GridPosition};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::core::Name;
+use bevy::prelude::{Entity, World};
+
+const REVEAL_GRAB_TURNS: u32 = 3;
+
+/// Drops an entity's disguise, letting it act, and has it grab the player if they're adjacent
+pub struct RevealAction {
+    pub entity: Entity,
+}
+
+impl Action for RevealAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<Disguise>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let disguise = match world.entity_mut(self.entity).remove::<Disguise>() {
+            Some(disguise) => disguise,
+            None => return ActionStatus::Finished,
+        };
+
+        world
+            .entity_mut(self.entity)
+            .insert(Name::new(disguise.true_name))
+            .insert(disguise.true_material)
+            .insert(disguise.actor);
+
+        let player = ImmutableWorld::new(world).player_entity();
+        if let Some(player) = player {
+            if world.get::<GridPosition>(player).is_some() {
+                world.add_action(GrabAction {
+                    grabber: self.entity,
+                    target: player,
+                    turns: REVEAL_GRAB_TURNS,
+                });
+            }
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,25 @@
+use crate::actions::{Action, ActionStatus};
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::prelude::World;
+
+/// Marks the whole layout of the current floor as explored
+pub struct RevealMapAction;
+
+impl Action for RevealMapAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let mut dungeon_layout = world.get_resource_mut::<DungeonLayout>().unwrap();
+        let layout_positions = dungeon_layout
+            .floor_positions
+            .iter()
+            .chain(dungeon_layout.wall_positions.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        dungeon_layout.explored_positions.extend(layout_positions);
+        ActionStatus::Finished
+    }
+}
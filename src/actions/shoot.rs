@@ -0,0 +1,197 @@
+use crate::actions::{Action, ActionStack, ActionStatus, DamageAction};
+use crate::bundles::SpriteBundleExt;
+use crate::components::{Damageable, GridPosition};
+use crate::dungeon::{line, DungeonLayout};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::math::{IVec2, Rect, Vec2};
+use bevy::prelude::{Entity, SpriteBundle, Transform, World};
+use std::time::{Duration, Instant};
+
+/// Fires a projectile towards the target tile, travelling at most range tiles
+/// The projectile stops at the first wall or occupant in its way, damaging it if it can be hurt
+pub struct ShootAction {
+    pub shooter: Entity,
+    pub target: IVec2,
+    pub range: i32,
+    pub damage: u32,
+}
+
+impl Action for ShootAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        match world.get::<GridPosition>(self.shooter) {
+            Some(position) => **position != self.target,
+            None => false,
+        }
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let origin = **world.get::<GridPosition>(self.shooter).unwrap();
+        let line_of_fire = LineOfFire::trace(
+            origin,
+            self.target,
+            self.range,
+            &mut ImmutableWorld::new(world),
+        );
+
+        let mut actions = Vec::<Box<dyn Action>>::new();
+        actions.push(Box::new(ProjectileAnimationAction::new(
+            line_of_fire.path,
+            "projectile",
+            Vec2::splat(8.0),
+        )));
+        if let Some(hit) = line_of_fire.hit {
+            if world.get::<Damageable>(hit).is_some() {
+                actions.push(Box::new(DamageAction {
+                    target: hit,
+                    amount: self.damage,
+                }));
+            }
+        }
+
+        world
+            .get_resource_mut::<ActionStack>()
+            .unwrap()
+            .add_sequence(actions);
+        ActionStatus::Finished
+    }
+}
+
+/// The tiles a projectile passes through, and whatever it hits at the end
+pub struct LineOfFire {
+    /// Excludes the origin, includes the tile of whatever was hit
+    pub path: Vec<IVec2>,
+    pub hit: Option<Entity>,
+}
+
+impl LineOfFire {
+    /// Follows Bresenham's line from the origin to the target
+    /// Stops at the target, after range tiles, or at the first occupied or non-floor tile
+    pub fn trace(origin: IVec2, target: IVec2, range: i32, world: &mut ImmutableWorld) -> Self {
+        let occupants = world
+            .query::<(&GridPosition, Entity)>()
+            .iter(world)
+            .map(|(position, entity)| (**position, entity))
+            .collect::<Vec<_>>();
+        let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+        Self::trace_with(origin, target, range, dungeon_layout, |position| {
+            occupants
+                .iter()
+                .find(|(occupant_position, _)| *occupant_position == position)
+                .map(|(_, entity)| *entity)
+        })
+    }
+
+    /// Like trace, for callers that look up what occupies a tile themselves
+    pub fn trace_with<F>(
+        origin: IVec2,
+        target: IVec2,
+        range: i32,
+        dungeon_layout: &DungeonLayout,
+        occupant_at: F,
+    ) -> Self
+    where
+        F: Fn(IVec2) -> Option<Entity>,
+    {
+        let mut path = Vec::new();
+        for position in line(origin, target)
+            .into_iter()
+            .skip(1)
+            .take(range.max(0) as usize)
+        {
+            path.push(position);
+            let hit = occupant_at(position);
+            if hit.is_some() || !dungeon_layout.is_floor(position) {
+                return Self { path, hit };
+            }
+        }
+        Self { path, hit: None }
+    }
+
+    /// Where something that bursts on impact goes off,
+    /// which is short of the end of the path if it ran into a wall
+    pub fn burst_position(&self, origin: IVec2, dungeon_layout: &DungeonLayout) -> IVec2 {
+        self.path
+            .iter()
+            .rev()
+            .copied()
+            .find(|position| dungeon_layout.is_floor(*position))
+            .unwrap_or(origin)
+    }
+}
+
+/// Moves a sprite along the path, one tile per step, skipped if none of the path is on screen
+pub struct ProjectileAnimationAction {
+    positions: Vec<IVec2>,
+    material: String,
+    size: Vec2,
+    projectile: Option<Entity>,
+    started: Option<Instant>,
+    step_duration: Duration,
+}
+
+impl ProjectileAnimationAction {
+    pub fn new(positions: Vec<IVec2>, material: &str, size: Vec2) -> Self {
+        Self {
+            positions,
+            material: material.to_owned(),
+            size,
+            projectile: None,
+            started: None,
+            step_duration: Duration::from_millis(30),
+        }
+    }
+}
+
+impl Action for ProjectileAnimationAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        let started = match self.started {
+            Some(started) => started,
+            None => {
+                let any_visible = self.positions.iter().any(|position| {
+                    let (x, y) = ((position.x * 32) as f32, (position.y * 32) as f32);
+                    world.is_rect_visible(Rect {
+                        left: x - 16.0,
+                        right: x + 16.0,
+                        top: y + 16.0,
+                        bottom: y - 16.0,
+                    })
+                });
+                if !any_visible {
+                    return ActionStatus::Finished;
+                }
+
+                let first = self.positions[0];
+                let mut sprite = SpriteBundle::new_overlay(&self.material, first.x, first.y);
+                sprite.sprite.size = self.size;
+                sprite.transform.translation.z = 2.0;
+                self.projectile = Some(world.spawn().insert_bundle(sprite).id());
+                let now = Instant::now();
+                self.started = Some(now);
+                now
+            }
+        };
+
+        let projectile = self.projectile.unwrap();
+        let step = (started.elapsed().as_millis() / self.step_duration.as_millis()) as usize;
+        match self.positions.get(step) {
+            Some(position) => {
+                let mut transform = world.get_mut::<Transform>(projectile).unwrap();
+                transform.translation.x = (position.x * 32) as f32;
+                transform.translation.y = (position.y * 32) as f32;
+                ActionStatus::Unfinished
+            }
+            None => {
+                world.despawn(projectile);
+                ActionStatus::Finished
+            }
+        }
+    }
+}
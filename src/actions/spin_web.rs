@@ -0,0 +1,37 @@
+use crate::actions::{Action, ActionStatus};
+use crate::bundles::Web;
+use crate::components::{TileEffect, TileEffectKind};
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::World;
+
+/// Covers a floor tile in web, unless it's already webbed
+pub struct SpinWebAction {
+    pub position: IVec2,
+}
+
+impl Action for SpinWebAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world
+            .get_resource::<DungeonLayout>()
+            .unwrap()
+            .is_floor(self.position)
+            && !is_webbed(self.position, world)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if self.can_perform(&mut ImmutableWorld::new(world)) {
+            world
+                .spawn()
+                .insert_bundle(Web::new(self.position.x, self.position.y));
+        }
+        ActionStatus::Finished
+    }
+}
+
+pub fn is_webbed(position: IVec2, world: &mut ImmutableWorld) -> bool {
+    world.query::<&TileEffect>().iter(world).any(|tile_effect| {
+        tile_effect.kind == TileEffectKind::Web && tile_effect.position == position
+    })
+}
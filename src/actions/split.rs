@@ -0,0 +1,122 @@
+use crate::actions::{can_enter, Action, ActionStatus, Direction};
+use crate::bundles::SpriteBundleExt;
+use crate::components::{
+    Actor, CombatStats, Damageable, GridPosition, InflictsOnHit, Splitting, ToxicTrail, Viewshed,
+};
+use crate::world::ImmutableWorld;
+use bevy::core::Name;
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, Handle, SpriteBundle, World};
+use bevy::sprite::{ColorMaterial, Sprite};
+use rand::seq::SliceRandom;
+
+/// Shrinks a splitting monster and buds off a copy of it next to it,
+/// sharing its remaining health between them
+/// Health and attack shrink in proportion to its size
+pub struct SplitAction {
+    pub entity: Entity,
+}
+
+impl Action for SplitAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world
+            .get::<Splitting>(self.entity)
+            .map(|splitting| splitting.can_split())
+            .unwrap_or(false)
+            && world
+                .get::<Damageable>(self.entity)
+                .map(|damageable| damageable.health >= 2)
+                .unwrap_or(false)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let position = **world.get::<GridPosition>(self.entity).unwrap();
+        let mut directions = Direction::ALL;
+        directions.shuffle(&mut rand::thread_rng());
+        let bud_position = match directions
+            .iter()
+            .map(|direction| position + direction.offset())
+            .find(|bud_position| {
+                can_enter(
+                    self.entity,
+                    position,
+                    *bud_position,
+                    &mut ImmutableWorld::new(world),
+                )
+            }) {
+            Some(bud_position) => bud_position,
+            // No room to split into
+            None => return ActionStatus::Finished,
+        };
+
+        let (old_size, splitting) = {
+            let mut splitting = world.get_mut::<Splitting>(self.entity).unwrap();
+            splitting.size -= 1;
+            (
+                splitting.size + 1,
+                Splitting {
+                    size: splitting.size,
+                    max_size: splitting.max_size,
+                    min_size: splitting.min_size,
+                    damage_threshold: splitting.damage_threshold,
+                },
+            )
+        };
+        let shrink = |value: u32| (value * splitting.size / old_size).max(1);
+        let (bud_health, max_health) = {
+            let mut damageable = world.get_mut::<Damageable>(self.entity).unwrap();
+            let max_health = shrink(damageable.max_health);
+            let bud_health = (damageable.health / 2).min(max_health);
+            damageable.max_health = max_health;
+            damageable.health = (damageable.health - bud_health).min(max_health);
+            (bud_health, max_health)
+        };
+        let combat_stats = {
+            let mut combat_stats = world.get_mut::<CombatStats>(self.entity).unwrap();
+            combat_stats.attack = shrink(combat_stats.attack);
+            CombatStats::new(combat_stats.attack, combat_stats.defense)
+        };
+        let sprite_size = Vec2::splat(32.0 * splitting.sprite_scale());
+        world.get_mut::<Sprite>(self.entity).unwrap().size = sprite_size;
+
+        let name = world.get::<Name>(self.entity).unwrap().clone();
+        let actor = world.get::<Actor>(self.entity).unwrap().clone();
+        let viewshed = Viewshed::new(world.get::<Viewshed>(self.entity).unwrap().radius);
+        let toxic_trail = world
+            .get::<ToxicTrail>(self.entity)
+            .map(|toxic_trail| ToxicTrail::new(toxic_trail.puddle_turns));
+        let inflicts_on_hit = world
+            .get::<InflictsOnHit>(self.entity)
+            .map(|inflicts_on_hit| InflictsOnHit(inflicts_on_hit.0));
+        let material = world
+            .get::<Handle<ColorMaterial>>(self.entity)
+            .unwrap()
+            .clone();
+        let mut sprite = SpriteBundle::from_material(material, bud_position.x, bud_position.y);
+        sprite.sprite.size = sprite_size;
+
+        let mut bud = world.spawn();
+        bud.insert_bundle(sprite)
+            .insert(name)
+            .insert(GridPosition::new(bud_position.x, bud_position.y))
+            .insert(Damageable {
+                health: bud_health,
+                max_health,
+            })
+            .insert(combat_stats)
+            .insert(actor)
+            .insert(viewshed)
+            .insert(splitting);
+        if let Some(toxic_trail) = toxic_trail {
+            bud.insert(toxic_trail);
+        }
+        if let Some(inflicts_on_hit) = inflicts_on_hit {
+            bud.insert(inflicts_on_hit);
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,36 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::{has_status_effect, StatusEffectKind, StatusEffects};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, World};
+
+/// How many turns of being immobilized a struggle takes off, on top of the turn passing
+const STRUGGLE_TURNS: u32 = 1;
+
+/// Spends a turn working free of whatever is holding an entity in place
+/// Being held wears off on its own too, struggling just speeds it up
+pub struct StruggleAction {
+    pub entity: Entity,
+}
+
+impl Action for StruggleAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        has_status_effect(world, self.entity, StatusEffectKind::Immobilized)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if let Some(mut status_effects) = world.get_mut::<StatusEffects>(self.entity) {
+            let freed = match status_effects.get_mut(StatusEffectKind::Immobilized) {
+                Some(immobilized) => {
+                    immobilized.turns_remaining =
+                        immobilized.turns_remaining.saturating_sub(STRUGGLE_TURNS);
+                    immobilized.turns_remaining == 0
+                }
+                None => false,
+            };
+            if freed {
+                status_effects.remove(StatusEffectKind::Immobilized);
+            }
+        }
+        ActionStatus::Finished
+    }
+}
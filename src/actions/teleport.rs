@@ -0,0 +1,48 @@
+use crate::actions::{Action, ActionStatus};
+use crate::components::GridPosition;
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, Transform, World};
+use rand::seq::IteratorRandom;
+use std::collections::HashSet;
+
+/// Moves the entity to a random unoccupied floor tile anywhere on the floor
+pub struct TeleportAction {
+    pub entity: Entity,
+}
+
+impl Action for TeleportAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get::<GridPosition>(self.entity).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let occupied_positions = world
+            .query::<&GridPosition>()
+            .iter(world)
+            .map(|position| **position)
+            .collect::<HashSet<_>>();
+        let destination = match world
+            .get_resource::<DungeonLayout>()
+            .unwrap()
+            .floor_positions
+            .iter()
+            .filter(|position| !occupied_positions.contains(position))
+            .choose(&mut rand::thread_rng())
+        {
+            Some(destination) => *destination,
+            None => return ActionStatus::Finished,
+        };
+
+        **world.get_mut::<GridPosition>(self.entity).unwrap() = destination;
+        if let Some(mut transform) = world.get_mut::<Transform>(self.entity) {
+            transform.translation.x = (destination.x * 32) as f32;
+            transform.translation.y = (destination.y * 32) as f32;
+        }
+        ActionStatus::Finished
+    }
+}
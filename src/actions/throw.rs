@@ -0,0 +1,152 @@
+use crate::actions::{
+    potion_action, Action, ActionStack, ActionStatus, ApplyStatusEffectAction, DamageAction,
+    LineOfFire, ProjectileAnimationAction,
+};
+use crate::bundles::ItemPile;
+use crate::components::{Actor, Damageable, GridPosition, Inventory};
+use crate::dungeon::DungeonLayout;
+use crate::items::{EquipmentSlot, Item, ItemAppearances, ItemKind, ItemStack};
+use crate::world::ImmutableWorld;
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{Entity, Or, With, World};
+
+pub const THROW_RANGE: i32 = 5;
+pub const SHATTER_RADIUS: i32 = 1;
+
+/// Hurls one of the stack at the index of the thrower's inventory towards the target tile
+/// It flies like a projectile and lands on the last floor tile it reaches
+/// Potions shatter there, splashing every creature within the shatter radius with their effect,
+/// weapons damage whatever they hit, and anything that doesn't shatter is left lying where it lands
+pub struct ThrowAction {
+    pub thrower: Entity,
+    pub index: usize,
+    pub target: IVec2,
+}
+
+impl Action for ThrowAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        let position = match world.get::<GridPosition>(self.thrower) {
+            Some(position) => **position,
+            None => return false,
+        };
+        position != self.target
+            && world
+                .get::<Inventory>(self.thrower)
+                .map(|inventory| self.index < inventory.stacks.len())
+                .unwrap_or(false)
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let origin = **world.get::<GridPosition>(self.thrower).unwrap();
+        let item = world
+            .get_mut::<Inventory>(self.thrower)
+            .unwrap()
+            .take_one(self.index)
+            .unwrap();
+        let line_of_fire = LineOfFire::trace(
+            origin,
+            self.target,
+            THROW_RANGE,
+            &mut ImmutableWorld::new(world),
+        );
+        let landing =
+            line_of_fire.burst_position(origin, world.get_resource::<DungeonLayout>().unwrap());
+
+        let mut actions = Vec::<Box<dyn Action>>::new();
+        actions.push(Box::new(ProjectileAnimationAction::new(
+            line_of_fire.path,
+            &item.sprite,
+            Vec2::splat(16.0),
+        )));
+        match item.kind {
+            ItemKind::Consumable(effect) if effect.is_potion() => {
+                let splashed_positions = world
+                    .get_resource::<DungeonLayout>()
+                    .unwrap()
+                    .visible_positions(landing, SHATTER_RADIUS);
+                let splashed = world
+                    .query_filtered::<(&GridPosition, Entity), Or<(With<Actor>, With<Damageable>)>>(
+                    )
+                    .iter(world)
+                    .filter(|(position, _)| splashed_positions.contains(position))
+                    .map(|(_, entity)| entity)
+                    .collect::<Vec<_>>();
+                actions.extend(
+                    splashed
+                        .into_iter()
+                        .filter_map(|entity| potion_action(effect, entity)),
+                );
+                world
+                    .get_resource_mut::<ItemAppearances>()
+                    .unwrap()
+                    .identify(effect);
+            }
+            _ => {
+                if let Some(hit) = line_of_fire.hit {
+                    if world.get::<Damageable>(hit).is_some() {
+                        if let Some(damage) = thrown_damage(&item) {
+                            actions.push(Box::new(DamageAction {
+                                target: hit,
+                                amount: damage,
+                            }));
+                            for effect in item.on_hit_effects() {
+                                actions.push(Box::new(ApplyStatusEffectAction {
+                                    target: hit,
+                                    effect,
+                                }));
+                            }
+                        }
+                    }
+                }
+                actions.push(Box::new(LandAction {
+                    stack: Some(ItemStack::new(item, 1)),
+                    position: landing,
+                }));
+            }
+        }
+
+        world
+            .get_resource_mut::<ActionStack>()
+            .unwrap()
+            .add_sequence(actions);
+        ActionStatus::Finished
+    }
+}
+
+/// Weapons hit as hard as they add to attack, items made for throwing as hard as they're made to,
+/// and other items don't hurt
+fn thrown_damage(item: &Item) -> Option<u32> {
+    match item.kind {
+        ItemKind::Equipment {
+            slot: EquipmentSlot::Weapon,
+            ..
+        } => Some(item.modifiers().attack.max(1) as u32),
+        ItemKind::Thrown { damage } => Some(damage),
+        _ => None,
+    }
+}
+
+/// Leaves a thrown item lying where it landed, once it's done flying
+struct LandAction {
+    stack: Option<ItemStack>,
+    position: IVec2,
+}
+
+impl Action for LandAction {
+    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
+        true
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if let Some(stack) = self.stack.take() {
+            world
+                .spawn()
+                .insert_bundle(ItemPile::new(stack, self.position.x, self.position.y));
+        }
+        ActionStatus::Finished
+    }
+}
@@ -0,0 +1,122 @@
+use crate::actions::{
+    Action, ActionStack, ActionStatus, ApplyStatusEffectAction, ExplodeAction, HealAction,
+    IdentifyAction, LineOfFire, RevealMapAction, TeleportAction,
+};
+use crate::components::{GridPosition, Inventory, StatusEffect, StatusEffectKind};
+use crate::dungeon::DungeonLayout;
+use crate::items::{ConsumableEffect, ItemAppearances, ItemKind};
+use crate::world::{ImmutableWorld, WorldExt};
+use bevy::math::IVec2;
+use bevy::prelude::{Entity, World};
+
+const HEALING_AMOUNT: u32 = 10;
+const HASTE_TURNS: u32 = 10;
+const REGENERATION_TURNS: u32 = 15;
+pub const FIREBALL_RANGE: i32 = 7;
+pub const FIREBALL_RADIUS: i32 = 2;
+const FIREBALL_DAMAGE: u32 = 5;
+
+/// Uses up one of a consumable from the entity's inventory, identifying its kind
+/// Consumables that need a target are aimed at the given tile,
+/// without one they go off on the user, as when reading an unidentified scroll
+pub struct UseItemAction {
+    pub entity: Entity,
+    pub index: usize,
+    pub target: Option<IVec2>,
+}
+
+impl UseItemAction {
+    fn effect(&self, world: &World) -> Option<ConsumableEffect> {
+        let stack = world
+            .get::<Inventory>(self.entity)?
+            .stacks
+            .get(self.index)?;
+        match stack.item.kind {
+            ItemKind::Consumable(effect) => Some(effect),
+            _ => None,
+        }
+    }
+}
+
+impl Action for UseItemAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        self.effect(world).is_some()
+    }
+
+    fn perform(&mut self, world: &mut World) -> ActionStatus {
+        if !self.can_perform(&mut ImmutableWorld::new(world)) {
+            return ActionStatus::Finished;
+        }
+
+        let effect = self.effect(world).unwrap();
+        world
+            .get_mut::<Inventory>(self.entity)
+            .unwrap()
+            .take_one(self.index);
+        world
+            .get_resource_mut::<ItemAppearances>()
+            .unwrap()
+            .identify(effect);
+
+        match effect {
+            ConsumableEffect::Healing
+            | ConsumableEffect::Haste
+            | ConsumableEffect::Regeneration => world
+                .get_resource_mut::<ActionStack>()
+                .unwrap()
+                .add(potion_action(effect, self.entity).unwrap()),
+            ConsumableEffect::Teleportation => world.add_action(TeleportAction {
+                entity: self.entity,
+            }),
+            ConsumableEffect::MagicMapping => world.add_action(RevealMapAction),
+            ConsumableEffect::Fireball => {
+                // Bursts on the first thing in its way
+                let origin = match world.get::<GridPosition>(self.entity) {
+                    Some(position) => **position,
+                    None => return ActionStatus::Finished,
+                };
+                let position = match self.target {
+                    Some(target) => {
+                        let line_of_fire = LineOfFire::trace(
+                            origin,
+                            target,
+                            FIREBALL_RANGE,
+                            &mut ImmutableWorld::new(world),
+                        );
+                        line_of_fire
+                            .burst_position(origin, world.get_resource::<DungeonLayout>().unwrap())
+                    }
+                    None => origin,
+                };
+                world.add_action(ExplodeAction {
+                    position,
+                    radius: FIREBALL_RADIUS,
+                    damage: FIREBALL_DAMAGE,
+                });
+            }
+            ConsumableEffect::Identify => world.add_action(IdentifyAction {
+                entity: self.entity,
+            }),
+        }
+        ActionStatus::Finished
+    }
+}
+
+/// What a potion does to whoever drinks it or is splashed by it, None for anything else
+pub fn potion_action(effect: ConsumableEffect, target: Entity) -> Option<Box<dyn Action>> {
+    match effect {
+        ConsumableEffect::Healing => Some(Box::new(HealAction {
+            target,
+            amount: HEALING_AMOUNT,
+        })),
+        ConsumableEffect::Haste => Some(Box::new(ApplyStatusEffectAction {
+            target,
+            effect: StatusEffect::new(StatusEffectKind::Haste, HASTE_TURNS, 0),
+        })),
+        ConsumableEffect::Regeneration => Some(Box::new(ApplyStatusEffectAction {
+            target,
+            effect: StatusEffect::new(StatusEffectKind::Regeneration, REGENERATION_TURNS, 1),
+        })),
+        _ => None,
+    }
+}
@@ -2,17 +2,17 @@ use crate::actions::{Action, ActionStatus};
 use crate::world::ImmutableWorld;
 use bevy::prelude::{Entity, World};
 
-pub struct PrintEntityAction {
+/// Passes an entity's turn without doing anything
+pub struct WaitAction {
     pub entity: Entity,
 }
 
-impl Action for PrintEntityAction {
-    fn can_perform(&self, _: &mut ImmutableWorld) -> bool {
-        true
+impl Action for WaitAction {
+    fn can_perform(&self, world: &mut ImmutableWorld) -> bool {
+        world.get_entity(self.entity).is_some()
     }
 
     fn perform(&mut self, _: &mut World) -> ActionStatus {
-        println!("Entity {:?} is acting", self.entity);
         ActionStatus::Finished
     }
 }
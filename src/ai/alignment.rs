@@ -0,0 +1,29 @@
+use crate::actions::Direction;
+use crate::components::GridPosition;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+
+/// If the target is in a straight line from the origin, within range,
+/// with nothing in between, returns the direction of that line
+pub fn clear_line_direction(
+    origin: IVec2,
+    target: IVec2,
+    range: i32,
+    world: &mut ImmutableWorld,
+) -> Option<Direction> {
+    let offset = target - origin;
+    let distance = offset.x.abs() + offset.y.abs();
+    if (offset.x != 0 && offset.y != 0) || distance == 0 || distance > range {
+        return None;
+    }
+
+    let direction = Direction::from_offset(offset / distance)?;
+    let mut positions = world.query::<&GridPosition>();
+    for step in 1..distance {
+        let position = origin + direction.offset() * step;
+        if positions.iter(world).any(|p| **p == position) {
+            return None;
+        }
+    }
+    Some(direction)
+}
@@ -0,0 +1,260 @@
+use crate::actions::Action;
+use crate::ai::{player_position_if_visible, step_towards};
+use crate::components::Brain;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(Behavior);
+/// A reusable piece of decision making, composed into trees to build brains
+pub trait Behavior: DynClone + Send + Sync {
+    /// Returns None if the behavior doesn't apply right now
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>>;
+
+    /// Called once at the start of every turn, for behaviors that keep track of time
+    fn tick(&mut self) {}
+}
+
+impl<F> Behavior for F
+where
+    F: (Fn(Entity, &mut ImmutableWorld) -> Option<Box<dyn Action>>) + DynClone + Send + Sync,
+{
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        (self)(this_entity, world)
+    }
+}
+
+clone_trait_object!(Condition);
+pub trait Condition: DynClone + Send + Sync {
+    fn check(&self, this_entity: Entity, world: &mut ImmutableWorld) -> bool;
+}
+
+impl<F> Condition for F
+where
+    F: (Fn(Entity, &mut ImmutableWorld) -> bool) + DynClone + Send + Sync,
+{
+    fn check(&self, this_entity: Entity, world: &mut ImmutableWorld) -> bool {
+        (self)(this_entity, world)
+    }
+}
+
+/// A brain that defers to a tree of behaviors
+/// After hearing an alarm it heads for where the player was seen until it sees them itself
+#[derive(Clone)]
+pub struct BehaviorBrain {
+    root: Box<dyn Behavior>,
+    alarm_position: Option<IVec2>,
+    hears_alarms: bool,
+}
+
+impl BehaviorBrain {
+    pub fn new<B: Behavior + 'static>(root: B) -> Self {
+        Self {
+            root: Box::new(root),
+            alarm_position: None,
+            hears_alarms: true,
+        }
+    }
+
+    /// For monsters that keep to their spot no matter what
+    pub fn ignoring_alarms(mut self) -> Self {
+        self.hears_alarms = false;
+        self
+    }
+}
+
+impl Brain for BehaviorBrain {
+    fn decide_action(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        if player_position_if_visible(this_entity, world).is_some() {
+            self.alarm_position = None;
+        }
+        if let Some(alarm_position) = self.alarm_position {
+            let action = step_towards(this_entity, alarm_position, world);
+            if action.is_some() {
+                return action;
+            }
+            self.alarm_position = None;
+        }
+        self.root.decide(this_entity, world)
+    }
+
+    fn hear_alarm(&mut self, player_position: IVec2) {
+        if self.hears_alarms {
+            self.alarm_position = Some(player_position);
+        }
+    }
+
+    fn start_turn(&mut self) {
+        self.root.tick();
+    }
+}
+
+/// Picks the first child that decides on an action
+#[derive(Clone)]
+pub struct Selector {
+    children: Vec<Box<dyn Behavior>>,
+}
+
+impl Selector {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+        }
+    }
+
+    pub fn or<B: Behavior + 'static>(mut self, child: B) -> Self {
+        self.children.push(Box::new(child));
+        self
+    }
+}
+
+impl Behavior for Selector {
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        self.children
+            .iter_mut()
+            .find_map(|child| child.decide(this_entity, world))
+    }
+
+    fn tick(&mut self) {
+        for child in self.children.iter_mut() {
+            child.tick();
+        }
+    }
+}
+
+/// Runs its children one after another, one per decision
+/// Starts over from the first child once they've all run, or as soon as one doesn't apply
+#[derive(Clone)]
+pub struct Sequence {
+    children: Vec<Box<dyn Behavior>>,
+    current: usize,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            current: 0,
+        }
+    }
+
+    pub fn then<B: Behavior + 'static>(mut self, child: B) -> Self {
+        self.children.push(Box::new(child));
+        self
+    }
+}
+
+impl Behavior for Sequence {
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let action = self
+            .children
+            .get_mut(self.current)?
+            .decide(this_entity, world);
+        self.current = match action {
+            Some(_) => (self.current + 1) % self.children.len(),
+            None => 0,
+        };
+        action
+    }
+
+    fn tick(&mut self) {
+        for child in self.children.iter_mut() {
+            child.tick();
+        }
+    }
+}
+
+/// Only applies its child while the condition holds
+#[derive(Clone)]
+pub struct When {
+    condition: Box<dyn Condition>,
+    child: Box<dyn Behavior>,
+}
+
+impl When {
+    pub fn new<C: Condition + 'static, B: Behavior + 'static>(condition: C, child: B) -> Self {
+        Self {
+            condition: Box::new(condition),
+            child: Box::new(child),
+        }
+    }
+}
+
+impl Behavior for When {
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        if self.condition.check(this_entity, world) {
+            self.child.decide(this_entity, world)
+        } else {
+            None
+        }
+    }
+
+    fn tick(&mut self) {
+        self.child.tick();
+    }
+}
+
+/// Stops its child from applying again until a number of turns have passed
+#[derive(Clone)]
+pub struct Cooldown {
+    turns: u32,
+    turns_remaining: u32,
+    child: Box<dyn Behavior>,
+}
+
+impl Cooldown {
+    pub fn new<B: Behavior + 'static>(turns: u32, child: B) -> Self {
+        Self {
+            turns,
+            turns_remaining: 0,
+            child: Box::new(child),
+        }
+    }
+}
+
+impl Behavior for Cooldown {
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        if self.turns_remaining > 0 {
+            return None;
+        }
+        let action = self.child.decide(this_entity, world);
+        if action.is_some() {
+            self.turns_remaining = self.turns;
+        }
+        action
+    }
+
+    fn tick(&mut self) {
+        self.turns_remaining = self.turns_remaining.saturating_sub(1);
+        self.child.tick();
+    }
+}
@@ -0,0 +1,11 @@
+mod alignment;
+mod behavior;
+mod movement;
+mod tactics;
+mod utility;
+
+pub use alignment::*;
+pub use behavior::*;
+pub use movement::*;
+pub use tactics::*;
+pub use utility::*;
@@ -0,0 +1,133 @@
+use crate::actions::{
+    Action, AttackAction, Direction, MoveAction, OpenDoorAction, RevealAction, StruggleAction,
+};
+use crate::components::{
+    closed_door_at, has_status_effect, Actor, Damageable, Disguise, Door, GridPosition, Inventory,
+    StatusEffectKind, TurnGroup, Viewshed,
+};
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::{Entity, With};
+use std::collections::HashSet;
+
+/// Attacks whatever hostile is in the way, otherwise moves, opening any closed door in the way
+/// The player bumping into something disguised reveals it instead
+/// Something held in place struggles instead of moving
+pub fn move_or_attack(
+    this_entity: Entity,
+    direction: Direction,
+    world: &mut ImmutableWorld,
+) -> Option<Box<dyn Action>> {
+    let target_position = **world.get::<GridPosition>(this_entity)? + direction.offset();
+    let this_turn_group = world.get::<Actor>(this_entity)?.turn_group;
+    if this_turn_group == TurnGroup::Player {
+        let disguised = world
+            .query_filtered::<(&GridPosition, Entity), With<Disguise>>()
+            .iter(world)
+            .find(|(position, _)| ***position == target_position)
+            .map(|(_, entity)| entity);
+        if let Some(entity) = disguised {
+            return RevealAction { entity }.to_brain_decision();
+        }
+    }
+    let target = world
+        .query::<(&GridPosition, &Actor, &Damageable, Entity)>()
+        .iter(world)
+        .find(|(position, actor, _, _)| {
+            ***position == target_position && is_hostile(this_turn_group, actor.turn_group)
+        })
+        .map(|(_, _, _, entity)| entity);
+
+    match target {
+        Some(target) => AttackAction {
+            attacker: this_entity,
+            target,
+        }
+        .to_brain_decision_if_can_perform(world),
+        None if has_status_effect(world, this_entity, StatusEffectKind::Immobilized) => {
+            StruggleAction {
+                entity: this_entity,
+            }
+            .to_brain_decision()
+        }
+        None => open_door_or_move(this_entity, direction, world),
+    }
+}
+
+/// Opens a closed door in the way, otherwise moves
+pub fn open_door_or_move(
+    this_entity: Entity,
+    direction: Direction,
+    world: &mut ImmutableWorld,
+) -> Option<Box<dyn Action>> {
+    let target_position = **world.get::<GridPosition>(this_entity)? + direction.offset();
+    match closed_door_at(target_position, world) {
+        Some(door) => OpenDoorAction {
+            entity: this_entity,
+            door,
+        }
+        .to_brain_decision_if_can_perform(world),
+        None => MoveAction {
+            entity: this_entity,
+            direction,
+        }
+        .to_brain_decision_if_can_perform(world),
+    }
+}
+
+/// Positions with something in the entity's way, leaving out closed doors it could open
+pub fn blocked_positions(this_entity: Entity, world: &mut ImmutableWorld) -> HashSet<IVec2> {
+    let mut occupants = world.query::<(&GridPosition, Option<&Door>)>();
+    let inventory = world.get::<Inventory>(this_entity);
+    occupants
+        .iter(world)
+        .filter(|(_, door)| {
+            !door
+                .map(|door| door.can_be_opened_with(inventory))
+                .unwrap_or(false)
+        })
+        .map(|(position, _)| **position)
+        .collect()
+}
+
+/// Takes one step along the shortest path to the goal, routing around anything in the way
+/// other than doors it can open
+/// Attacks instead if the goal is adjacent and occupied by a hostile
+pub fn step_towards(
+    this_entity: Entity,
+    goal: IVec2,
+    world: &mut ImmutableWorld,
+) -> Option<Box<dyn Action>> {
+    let start = **world.get::<GridPosition>(this_entity)?;
+    let occupied_positions = blocked_positions(this_entity, world);
+    let path =
+        world
+            .get_resource::<DungeonLayout>()
+            .unwrap()
+            .find_path(start, goal, |position| {
+                position == goal || !occupied_positions.contains(&position)
+            })?;
+
+    let direction = Direction::from_offset(*path.first()? - start)?;
+    move_or_attack(this_entity, direction, world)
+}
+
+pub fn player_position_if_visible(
+    this_entity: Entity,
+    world: &mut ImmutableWorld,
+) -> Option<IVec2> {
+    let player = world.player_entity()?;
+    let player_position = **world.get::<GridPosition>(player)?;
+    if world.get::<Viewshed>(this_entity)?.can_see(player_position) {
+        Some(player_position)
+    } else {
+        None
+    }
+}
+
+pub fn is_hostile(this_turn_group: TurnGroup, other_turn_group: TurnGroup) -> bool {
+    this_turn_group != other_turn_group
+        && this_turn_group != TurnGroup::Neutral
+        && other_turn_group != TurnGroup::Neutral
+}
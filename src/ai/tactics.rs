@@ -0,0 +1,108 @@
+use crate::actions::{Action, Direction, LineOfFire, MoveAction, ShootAction, WaitAction};
+use crate::ai::{
+    clear_line_direction, player_position_if_visible, step_towards, Behavior, Condition,
+};
+use crate::components::{Damageable, GridPosition};
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+
+pub fn can_see_player(this_entity: Entity, world: &mut ImmutableWorld) -> bool {
+    player_position_if_visible(this_entity, world).is_some()
+}
+
+pub fn player_adjacent(this_entity: Entity, world: &mut ImmutableWorld) -> bool {
+    let position = match world.get::<GridPosition>(this_entity) {
+        Some(position) => **position,
+        None => return false,
+    };
+    player_position_if_visible(this_entity, world)
+        .map(|player_position| {
+            let offset = player_position - position;
+            offset.x.abs() + offset.y.abs() == 1
+        })
+        .unwrap_or(false)
+}
+
+/// Whether the entity has less than the given percentage of its health left
+pub fn health_below(percent: u32) -> impl Condition {
+    move |this_entity: Entity, world: &mut ImmutableWorld| {
+        world
+            .get::<Damageable>(this_entity)
+            .map(|damageable| damageable.health * 100 < damageable.max_health * percent)
+            .unwrap_or(false)
+    }
+}
+
+pub fn wait(this_entity: Entity, _: &mut ImmutableWorld) -> Option<Box<dyn Action>> {
+    WaitAction {
+        entity: this_entity,
+    }
+    .to_brain_decision()
+}
+
+/// Heads for the player, attacking once next to them
+pub fn approach_player(this_entity: Entity, world: &mut ImmutableWorld) -> Option<Box<dyn Action>> {
+    let player_position = player_position_if_visible(this_entity, world)?;
+    step_towards(this_entity, player_position, world)
+}
+
+/// Steps to whichever neighboring tile is furthest from the player, if any is further than here
+pub fn flee_from_player(
+    this_entity: Entity,
+    world: &mut ImmutableWorld,
+) -> Option<Box<dyn Action>> {
+    let position = **world.get::<GridPosition>(this_entity)?;
+    let player_position = player_position_if_visible(this_entity, world)?;
+    let distance_from_player = |position: IVec2| {
+        let offset = player_position - position;
+        offset.x.abs() + offset.y.abs()
+    };
+
+    let mut best_move = None;
+    let mut best_distance = distance_from_player(position);
+    for direction in Direction::ALL {
+        let move_action = MoveAction {
+            entity: this_entity,
+            direction,
+        };
+        let distance = distance_from_player(position + direction.offset());
+        if distance > best_distance && move_action.can_perform(world) {
+            best_distance = distance;
+            best_move = Some(move_action);
+        }
+    }
+    best_move?.to_brain_decision()
+}
+
+/// Uses the given attack in the player's direction when they're in a clear straight line within range
+pub fn ranged_attack_if_aligned<F>(range: i32, attack: F) -> impl Behavior
+where
+    F: (Fn(Entity, Direction) -> Box<dyn Action>) + Clone + Send + Sync + 'static,
+{
+    move |this_entity: Entity, world: &mut ImmutableWorld| {
+        let position = **world.get::<GridPosition>(this_entity)?;
+        let player_position = player_position_if_visible(this_entity, world)?;
+        let direction = clear_line_direction(position, player_position, range, world)?;
+        Some(attack(this_entity, direction))
+    }
+}
+
+/// Shoots at the player when they're visible, within range and nothing else is in the line of fire
+pub fn shoot_player_if_in_line_of_fire(range: i32, damage: u32) -> impl Behavior {
+    move |this_entity: Entity, world: &mut ImmutableWorld| {
+        let position = **world.get::<GridPosition>(this_entity)?;
+        let player_position = player_position_if_visible(this_entity, world)?;
+        let player = world.player_entity()?;
+        if LineOfFire::trace(position, player_position, range, world).hit != Some(player) {
+            return None;
+        }
+        ShootAction {
+            shooter: this_entity,
+            target: player_position,
+            range,
+            damage,
+        }
+        .to_brain_decision()
+    }
+}
@@ -0,0 +1,64 @@
+use crate::actions::Action;
+use crate::ai::Behavior;
+use crate::world::ImmutableWorld;
+use bevy::prelude::Entity;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(Score);
+/// Rates how good an option is right now, or None if it's not an option at all
+pub trait Score: DynClone + Send + Sync {
+    fn score(&self, this_entity: Entity, world: &mut ImmutableWorld) -> Option<i32>;
+}
+
+impl<F> Score for F
+where
+    F: (Fn(Entity, &mut ImmutableWorld) -> Option<i32>) + DynClone + Send + Sync,
+{
+    fn score(&self, this_entity: Entity, world: &mut ImmutableWorld) -> Option<i32> {
+        (self)(this_entity, world)
+    }
+}
+
+/// Tries its options from the highest scoring down, until one decides on an action
+/// Options with equal scores are tried in the order they were added
+#[derive(Clone)]
+pub struct Utility {
+    options: Vec<(Box<dyn Score>, Box<dyn Behavior>)>,
+}
+
+impl Utility {
+    pub fn new() -> Self {
+        Self {
+            options: Vec::new(),
+        }
+    }
+
+    pub fn option<S: Score + 'static, B: Behavior + 'static>(mut self, score: S, child: B) -> Self {
+        self.options.push((Box::new(score), Box::new(child)));
+        self
+    }
+}
+
+impl Behavior for Utility {
+    fn decide(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let mut scored_options = self
+            .options
+            .iter_mut()
+            .filter_map(|(score, child)| Some((score.score(this_entity, world)?, child)))
+            .collect::<Vec<_>>();
+        scored_options.sort_by_key(|(score, _)| -score);
+        scored_options
+            .into_iter()
+            .find_map(|(_, child)| child.decide(this_entity, world))
+    }
+
+    fn tick(&mut self) {
+        for (_, child) in self.options.iter_mut() {
+            child.tick();
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{BlocksSight, Door, GridPosition, Terrain};
+use bevy::core::Name;
+use bevy::math::IVec2;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+#[derive(Bundle)]
+pub struct ClosedDoor {
+    name: Name,
+    door: Door,
+    position: GridPosition,
+    terrain: Terrain,
+    blocks_sight: BlocksSight,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl ClosedDoor {
+    /// A locked door needs the key to the floor in the lock to be opened
+    pub fn new(x: i32, y: i32, lock: Option<u32>) -> Self {
+        let (name, sprite) = match lock {
+            Some(_) => ("Locked Door", "door_locked"),
+            None => ("Door", "door_closed"),
+        };
+        Self {
+            name: Name::new(name),
+            door: Door {
+                position: IVec2::new(x, y),
+                open: false,
+                lock,
+            },
+            position: GridPosition::new(x, y),
+            terrain: Terrain,
+            blocks_sight: BlocksSight,
+            sprite: SpriteBundle::new_overlay(sprite, x, y),
+        }
+    }
+}
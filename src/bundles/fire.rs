@@ -0,0 +1,22 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{TileEffect, TileEffectKind};
+use bevy::core::Name;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+#[derive(Bundle)]
+pub struct Fire {
+    name: Name,
+    tile_effect: TileEffect,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl Fire {
+    pub fn new(x: i32, y: i32, turns: u32) -> Self {
+        Self {
+            name: Name::new("Fire"),
+            tile_effect: TileEffect::new(x, y, TileEffectKind::Fire, Some(turns)),
+            sprite: SpriteBundle::new_overlay("fire", x, y),
+        }
+    }
+}
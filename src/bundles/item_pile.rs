@@ -0,0 +1,26 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::FloorItem;
+use crate::items::ItemStack;
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{Bundle, SpriteBundle};
+
+#[derive(Bundle)]
+pub struct ItemPile {
+    floor_item: FloorItem,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl ItemPile {
+    pub fn new(stack: ItemStack, x: i32, y: i32) -> Self {
+        let mut sprite = SpriteBundle::new_overlay(&stack.item.sprite, x, y);
+        sprite.sprite.size = Vec2::splat(16.0);
+        Self {
+            floor_item: FloorItem {
+                position: IVec2::new(x, y),
+                stack,
+            },
+            sprite,
+        }
+    }
+}
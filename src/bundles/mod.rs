@@ -1,11 +1,25 @@
+mod closed_door;
+mod fire;
 mod floor;
+mod item_pile;
+mod monster;
 mod player;
-mod skeleton_scout;
 mod sprite;
+mod stairs;
+mod tile_highlight;
+mod toxic_puddle;
 mod wall;
+mod web;
 
+pub use closed_door::*;
+pub use fire::*;
 pub use floor::*;
+pub use item_pile::*;
+pub use monster::*;
 pub use player::*;
-pub use skeleton_scout::*;
 pub use sprite::*;
+pub use stairs::*;
+pub use tile_highlight::*;
+pub use toxic_puddle::*;
 pub use wall::*;
+pub use web::*;
@@ -0,0 +1,35 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{Actor, CombatStats, Damageable, GridPosition, Viewshed};
+use crate::monsters::MonsterDefinition;
+use bevy::core::Name;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+/// The parts every monster has, see SpawnMonsterAction for the rest
+#[derive(Bundle)]
+pub struct Monster {
+    name: Name,
+    position: GridPosition,
+    damageable: Damageable,
+    combat_stats: CombatStats,
+    actor: Actor,
+    viewshed: Viewshed,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl Monster {
+    pub fn new(definition: &MonsterDefinition, x: i32, y: i32) -> Self {
+        Self {
+            name: Name::new(definition.name.clone()),
+            position: GridPosition::new(x, y),
+            damageable: Damageable::new(definition.health),
+            combat_stats: CombatStats::new(definition.attack, definition.defense),
+            actor: definition
+                .brain
+                .actor(definition.faction)
+                .with_actions_per_turn(definition.speed),
+            viewshed: Viewshed::new(definition.sight_radius),
+            sprite: SpriteBundle::from_material(definition.material.clone(), x, y),
+        }
+    }
+}
@@ -1,16 +1,38 @@
-use crate::actions::{Action, Direction, MoveAction};
+use crate::actions::{
+    Action, CloseDoorAction, DescendAction, Direction, DropAction, EquipAction, PickUpAction,
+    UnequipAction, UseItemAction, WaitAction,
+};
+use crate::ai::{blocked_positions, move_or_attack, open_door_or_move};
 use crate::bundles::SpriteBundleExt;
-use crate::components::{Actor, Brain, Damageable, GridPosition, KeepBetweenFloors, TurnGroup};
+use crate::components::{
+    closed_door_at, Actor, Brain, CombatStats, Damageable, Door, Equipment, GridPosition,
+    Inventory, KeepBetweenFloors, NaturalRegeneration, TurnGroup, Viewshed,
+};
+use crate::dungeon::DungeonLayout;
+use crate::input::{AutoExploreConfig, CursorTile, LatchedCommands, PlayerCommand};
+use crate::items::{EquipmentSlot, ItemAppearances, ItemKind};
+use crate::ui::Targeting;
 use crate::world::ImmutableWorld;
+use bevy::core::Name;
 use bevy::input::Input;
-use bevy::prelude::{Bundle, Entity, KeyCode, SpriteBundle};
+use bevy::math::IVec2;
+use bevy::prelude::{Bundle, Entity, SpriteBundle};
+use std::num::NonZeroU32;
 use std::time::{Duration, Instant};
 
+const INVENTORY_CAPACITY: usize = 16;
+
 #[derive(Bundle)]
 pub struct Player {
+    name: Name,
     position: GridPosition,
     damageable: Damageable,
+    regeneration: NaturalRegeneration,
+    combat_stats: CombatStats,
     actor: Actor,
+    viewshed: Viewshed,
+    inventory: Inventory,
+    equipment: Equipment,
     #[bundle]
     sprite: SpriteBundle,
     kbf: KeepBetweenFloors,
@@ -19,9 +41,15 @@ pub struct Player {
 impl Player {
     pub fn new(x: i32, y: i32) -> Self {
         Self {
+            name: Name::new("You"),
             position: GridPosition::new(x, y),
-            damageable: Damageable::new(),
+            damageable: Damageable::new(20),
+            regeneration: NaturalRegeneration::new(NonZeroU32::new(8).unwrap()),
+            combat_stats: CombatStats::new(3, 1),
             actor: Actor::new(PlayerBrain::CanMoveOnce, TurnGroup::Player),
+            viewshed: Viewshed::new(8),
+            inventory: Inventory::new(INVENTORY_CAPACITY),
+            equipment: Equipment::default(),
             sprite: SpriteBundle::new("soul_spectre.png", x, y),
             kbf: KeepBetweenFloors,
         }
@@ -31,40 +59,169 @@ impl Player {
 #[derive(Clone)]
 enum PlayerBrain {
     CanMoveOnce,
-    MovedOnce { when: Instant },
+    MovedOnce {
+        when: Instant,
+    },
     MovingMany,
+    Traveling {
+        path: Vec<IVec2>,
+        interrupt_check: InterruptCheck,
+    },
+    Exploring {
+        interrupt_check: InterruptCheck,
+    },
+    Resting {
+        interrupt_check: InterruptCheck,
+    },
 }
 
 impl PlayerBrain {
     fn decide_action(this_entity: Entity, world: &mut ImmutableWorld) -> Option<Box<dyn Action>> {
-        let keyboard = world.get_resource::<Input<KeyCode>>().unwrap();
-        if keyboard.pressed(KeyCode::W) {
-            MoveAction {
+        let player_commands = world.get_resource::<Input<PlayerCommand>>().unwrap();
+        if player_commands.pressed(PlayerCommand::Wait) {
+            return WaitAction {
                 entity: this_entity,
-                direction: Direction::Up,
             }
-            .to_brain_decision_if_can_perform(world)
-        } else if keyboard.pressed(KeyCode::A) {
-            MoveAction {
+            .to_brain_decision();
+        }
+        if player_commands.just_pressed(PlayerCommand::Descend) {
+            return DescendAction {
                 entity: this_entity,
-                direction: Direction::Left,
             }
-            .to_brain_decision_if_can_perform(world)
-        } else if keyboard.pressed(KeyCode::S) {
-            MoveAction {
+            .to_brain_decision_if_can_perform(world);
+        }
+        if player_commands.just_pressed(PlayerCommand::PickUp) {
+            return PickUpAction {
                 entity: this_entity,
-                direction: Direction::Down,
+                categories: None,
             }
-            .to_brain_decision_if_can_perform(world)
-        } else if keyboard.pressed(KeyCode::D) {
-            MoveAction {
+            .to_brain_decision_if_can_perform(world);
+        }
+        if player_commands.just_pressed(PlayerCommand::Drop) {
+            let index = world.get::<Inventory>(this_entity)?.selected;
+            return DropAction {
+                entity: this_entity,
+                index,
+            }
+            .to_brain_decision_if_can_perform(world);
+        }
+        if player_commands.just_pressed(PlayerCommand::Use) {
+            // Known consumables that need a target are used through targeting mode instead
+            let inventory = world.get::<Inventory>(this_entity)?;
+            let index = inventory.selected;
+            if let Some(ItemKind::Consumable(effect)) = inventory
+                .stacks
+                .get(index)
+                .map(|stack| stack.item.kind.clone())
+            {
+                let appearances = world.get_resource::<ItemAppearances>().unwrap();
+                if effect.needs_target() && appearances.is_identified(effect) {
+                    return None;
+                }
+            }
+            return EquipAction {
                 entity: this_entity,
-                direction: Direction::Right,
+                index,
             }
             .to_brain_decision_if_can_perform(world)
-        } else {
-            None
+            .or_else(|| {
+                UseItemAction {
+                    entity: this_entity,
+                    index,
+                    target: None,
+                }
+                .to_brain_decision_if_can_perform(world)
+            });
+        }
+        if player_commands.just_pressed(PlayerCommand::CloseDoor) {
+            let position = **world.get::<GridPosition>(this_entity)?;
+            let door = world
+                .query::<(&Door, Entity)>()
+                .iter(world)
+                .find(|(door, _)| {
+                    let offset = door.position - position;
+                    door.open && offset.x.abs() + offset.y.abs() == 1
+                })
+                .map(|(_, door)| door)?;
+            return CloseDoorAction {
+                entity: this_entity,
+                door,
+            }
+            .to_brain_decision_if_can_perform(world);
+        }
+        for slot in EquipmentSlot::ALL {
+            if player_commands.just_pressed(PlayerCommand::Unequip(slot)) {
+                return UnequipAction {
+                    entity: this_entity,
+                    slot,
+                }
+                .to_brain_decision_if_can_perform(world);
+            }
+        }
+
+        let direction = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ]
+        .iter()
+        .copied()
+        .find(|direction| player_commands.pressed(PlayerCommand::Move(*direction)))?;
+
+        move_or_attack(this_entity, direction, world)
+    }
+
+    /// Paths through explored or visible floor tiles, avoiding anything currently in the way
+    /// other than doors that can be opened, tiles the player has never seen can't be traveled to
+    fn plan_travel(
+        this_entity: Entity,
+        goal: IVec2,
+        world: &mut ImmutableWorld,
+    ) -> Option<Vec<IVec2>> {
+        let start = **world.get::<GridPosition>(this_entity)?;
+        let occupied_positions = blocked_positions(this_entity, world);
+        let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+        let viewshed = world.get::<Viewshed>(this_entity);
+        let is_seen = |position| {
+            dungeon_layout.is_explored(position)
+                || viewshed.map(|viewshed| viewshed.can_see(position)) == Some(true)
+        };
+        if !is_seen(goal) {
+            return None;
         }
+        dungeon_layout.find_path(start, goal, |position| {
+            is_seen(position) && (position == goal || !occupied_positions.contains(&position))
+        })
+    }
+
+    /// Moves one step towards the nearest reachable unexplored area
+    fn decide_explore_step(
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let start = **world.get::<GridPosition>(this_entity)?;
+        let occupied_positions = blocked_positions(this_entity, world);
+        let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+        let path = dungeon_layout.find_path_to_nearest(
+            start,
+            |position| dungeon_layout.is_frontier(position),
+            |position| {
+                dungeon_layout.is_explored(position) && !occupied_positions.contains(&position)
+            },
+        )?;
+
+        let direction = Direction::from_offset(*path.first()? - start)?;
+        open_door_or_move(this_entity, direction, world)
+    }
+
+    fn pressed_commands(world: &mut ImmutableWorld) -> Vec<PlayerCommand> {
+        world
+            .get_resource::<Input<PlayerCommand>>()
+            .unwrap()
+            .get_pressed()
+            .copied()
+            .collect()
     }
 }
 
@@ -74,6 +231,51 @@ impl Brain for PlayerBrain {
         this_entity: Entity,
         world: &mut ImmutableWorld,
     ) -> Option<Box<dyn Action>> {
+        // Aimed abilities are chosen in targeting mode, see update_targeting
+        let targeting = world.get_resource::<Targeting>().unwrap();
+        if let Some((aim, target)) = targeting.confirmed.clone() {
+            *self = Self::CanMoveOnce;
+            return aim.action(this_entity, target, world);
+        }
+        if targeting.aim.is_some() {
+            *self = Self::CanMoveOnce;
+            return None;
+        }
+
+        // Clicking a tile starts traveling to it
+        let clicked_tile = world.get_resource::<CursorTile>().unwrap().clicked;
+        if let Some(goal) = clicked_tile {
+            if let Some(path) = Self::plan_travel(this_entity, goal, world) {
+                *self = Self::Traveling {
+                    path,
+                    interrupt_check: InterruptCheck::new(this_entity, world),
+                };
+            }
+        }
+
+        // Pressing auto-explore starts exploring, holding it doesn't restart after an interruption
+        let pressed_commands = Self::pressed_commands(world);
+        let auto_explore_pressed = world
+            .get_resource::<LatchedCommands>()
+            .unwrap()
+            .0
+            .contains(&PlayerCommand::AutoExplore);
+        if auto_explore_pressed && !matches!(self, Self::Exploring { .. }) {
+            *self = Self::Exploring {
+                interrupt_check: InterruptCheck::new(this_entity, world),
+            };
+        }
+        let rest_pressed = world
+            .get_resource::<LatchedCommands>()
+            .unwrap()
+            .0
+            .contains(&PlayerCommand::Rest);
+        if rest_pressed && !matches!(self, Self::Resting { .. }) {
+            *self = Self::Resting {
+                interrupt_check: InterruptCheck::new(this_entity, world),
+            };
+        }
+
         // Only allow consecutive movements if trying to move for at least 300ms
         match self {
             Self::CanMoveOnce => {
@@ -104,6 +306,118 @@ impl Brain for PlayerBrain {
                 }
                 action
             }
+            Self::Traveling {
+                path,
+                interrupt_check,
+            } => {
+                if path.is_empty()
+                    || !pressed_commands.is_empty()
+                    || interrupt_check.should_interrupt(this_entity, world)
+                {
+                    *self = Self::CanMoveOnce;
+                    return None;
+                }
+
+                // A closed door along the way is opened first, staying in front of it
+                let next_position = path[0];
+                if closed_door_at(next_position, world).is_none() {
+                    path.remove(0);
+                }
+                let current_position = **world.get::<GridPosition>(this_entity).unwrap();
+                let action = Direction::from_offset(next_position - current_position)
+                    .and_then(|direction| open_door_or_move(this_entity, direction, world));
+                if action.is_none() {
+                    *self = Self::CanMoveOnce;
+                }
+                action
+            }
+            Self::Exploring { interrupt_check } => {
+                let cancelled = pressed_commands
+                    .iter()
+                    .any(|command| *command != PlayerCommand::AutoExplore);
+                if cancelled || interrupt_check.should_interrupt(this_entity, world) {
+                    *self = Self::CanMoveOnce;
+                    return None;
+                }
+
+                // Picks up what the config asks for along the way
+                let categories = world
+                    .get_resource::<AutoExploreConfig>()
+                    .unwrap()
+                    .pick_up
+                    .clone();
+                let pick_up_action = PickUpAction {
+                    entity: this_entity,
+                    categories: Some(categories),
+                }
+                .to_brain_decision_if_can_perform(world);
+                if pick_up_action.is_some() {
+                    return pick_up_action;
+                }
+
+                // Stops once the floor is fully explored or the way is blocked
+                let action = Self::decide_explore_step(this_entity, world);
+                if action.is_none() {
+                    *self = Self::CanMoveOnce;
+                }
+                action
+            }
+            Self::Resting { interrupt_check } => {
+                let cancelled = pressed_commands
+                    .iter()
+                    .any(|command| *command != PlayerCommand::Rest);
+                let fully_healed = world
+                    .get::<Damageable>(this_entity)
+                    .map(|damageable| damageable.is_at_full_health())
+                    .unwrap_or(true);
+                if cancelled || fully_healed || interrupt_check.should_interrupt(this_entity, world)
+                {
+                    *self = Self::CanMoveOnce;
+                    return None;
+                }
+
+                WaitAction {
+                    entity: this_entity,
+                }
+                .to_brain_decision()
+            }
+        }
+    }
+}
+
+/// Remembers the player's health when starting a multi-turn activity,
+/// so that it can be stopped when the player is hurt or while any enemy is in view
+#[derive(Clone)]
+struct InterruptCheck {
+    health: u32,
+}
+
+impl InterruptCheck {
+    fn new(player: Entity, world: &mut ImmutableWorld) -> Self {
+        Self {
+            health: world
+                .get::<Damageable>(player)
+                .map(|damageable| damageable.health)
+                .unwrap_or(0),
         }
     }
+
+    fn should_interrupt(&self, player: Entity, world: &mut ImmutableWorld) -> bool {
+        let health = world
+            .get::<Damageable>(player)
+            .map(|damageable| damageable.health)
+            .unwrap_or(0);
+        health < self.health || enemy_in_view(player, world)
+    }
+}
+
+fn enemy_in_view(player: Entity, world: &mut ImmutableWorld) -> bool {
+    let mut actors = world.query::<(&Actor, &GridPosition)>();
+    let viewshed = match world.get::<Viewshed>(player) {
+        Some(viewshed) => viewshed,
+        None => return false,
+    };
+    actors.iter(world).any(|(actor, position)| {
+        actor.turn_group == TurnGroup::Enemy && viewshed.can_see(**position)
+    })
 }
@@ -1,19 +1,26 @@
 use bevy::math::{Vec2, Vec3};
 use bevy::prelude::{Handle, SpriteBundle, Transform};
+use bevy::render::draw::Visible;
 use bevy::sprite::{ColorMaterial, Sprite};
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 
 pub trait SpriteBundleExt {
     fn new(sprite: &str, x: i32, y: i32) -> Self;
+    fn from_material(material: Handle<ColorMaterial>, x: i32, y: i32) -> Self;
     fn new_background(sprite: &str, x: i32, y: i32) -> Self;
+    fn new_overlay(sprite: &str, x: i32, y: i32) -> Self;
 }
 
 impl SpriteBundleExt for SpriteBundle {
     fn new(sprite: &str, x: i32, y: i32) -> Self {
+        Self::from_material(MaterialMap::get(sprite), x, y)
+    }
+
+    fn from_material(material: Handle<ColorMaterial>, x: i32, y: i32) -> Self {
         Self {
             sprite: Sprite::new(Vec2::new(32.0, 32.0)),
-            material: MaterialMap::get(sprite),
+            material,
             transform: Transform {
                 translation: Vec3::new((x * 32) as f32, (y * 32) as f32, 1.0),
                 ..Default::default()
@@ -33,6 +40,23 @@ impl SpriteBundleExt for SpriteBundle {
             ..Default::default()
         }
     }
+
+    /// Drawn between the floor and whatever stands on it
+    fn new_overlay(sprite: &str, x: i32, y: i32) -> Self {
+        Self {
+            sprite: Sprite::new(Vec2::new(32.0, 32.0)),
+            material: MaterialMap::get(sprite),
+            transform: Transform {
+                translation: Vec3::new((x * 32) as f32, (y * 32) as f32, 0.5),
+                ..Default::default()
+            },
+            visible: Visible {
+                is_visible: true,
+                is_transparent: true,
+            },
+            ..Default::default()
+        }
+    }
 }
 
 pub static MATERIAL_MAP: MaterialMap = MaterialMap {
@@ -44,7 +68,7 @@ pub struct MaterialMap {
 }
 
 impl MaterialMap {
-    fn get(key: &str) -> Handle<ColorMaterial> {
+    pub fn get(key: &str) -> Handle<ColorMaterial> {
         MATERIAL_MAP
             .map
             .get()
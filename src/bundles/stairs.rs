@@ -0,0 +1,22 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{TileEffect, TileEffectKind};
+use bevy::core::Name;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+#[derive(Bundle)]
+pub struct Stairs {
+    name: Name,
+    tile_effect: TileEffect,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl Stairs {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self {
+            name: Name::new("Stairs Down"),
+            tile_effect: TileEffect::new(x, y, TileEffectKind::Stairs, None),
+            sprite: SpriteBundle::new_overlay("stairs", x, y),
+        }
+    }
+}
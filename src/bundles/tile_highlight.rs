@@ -0,0 +1,29 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::KeepBetweenFloors;
+use crate::ui::HoverHighlight;
+use bevy::prelude::{Bundle, SpriteBundle};
+use bevy::render::draw::Visible;
+
+#[derive(Bundle)]
+pub struct TileHighlight {
+    highlight: HoverHighlight,
+    #[bundle]
+    sprite: SpriteBundle,
+    kbf: KeepBetweenFloors,
+}
+
+impl TileHighlight {
+    pub fn new() -> Self {
+        let mut sprite = SpriteBundle::new("tile_highlight", 0, 0);
+        sprite.transform.translation.z = 2.0;
+        sprite.visible = Visible {
+            is_visible: false,
+            is_transparent: true,
+        };
+        Self {
+            highlight: HoverHighlight,
+            sprite,
+            kbf: KeepBetweenFloors,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{TileEffect, TileEffectKind};
+use bevy::core::Name;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+#[derive(Bundle)]
+pub struct ToxicPuddle {
+    name: Name,
+    tile_effect: TileEffect,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl ToxicPuddle {
+    pub fn new(x: i32, y: i32, turns: u32) -> Self {
+        Self {
+            name: Name::new("Toxic Puddle"),
+            tile_effect: TileEffect::new(x, y, TileEffectKind::Toxic, Some(turns)),
+            sprite: SpriteBundle::new_overlay("toxic_puddle", x, y),
+        }
+    }
+}
@@ -1,24 +1,24 @@
 use crate::bundles::SpriteBundleExt;
-use crate::components::GridPosition;
+use crate::components::{GridPosition, Terrain};
+use bevy::core::Name;
 use bevy::prelude::{Bundle, SpriteBundle};
-use rand::{thread_rng, Rng};
 
 #[derive(Bundle)]
 pub struct Wall {
+    name: Name,
     position: GridPosition,
+    terrain: Terrain,
     #[bundle]
     sprite: SpriteBundle,
 }
 
 impl Wall {
-    pub fn new(x: i32, y: i32) -> Self {
-        let sprite = if thread_rng().gen_ratio(1, 4) {
-            "wall_mossy.png"
-        } else {
-            "wall.png"
-        };
+    pub fn new(x: i32, y: i32, mossy: bool) -> Self {
+        let sprite = if mossy { "wall_mossy.png" } else { "wall.png" };
         Self {
+            name: Name::new("Wall"),
             position: GridPosition::new(x, y),
+            terrain: Terrain,
             sprite: SpriteBundle::new(sprite, x, y),
         }
     }
@@ -0,0 +1,27 @@
+use crate::bundles::SpriteBundleExt;
+use crate::components::{Flammable, TileEffect, TileEffectKind};
+use bevy::core::Name;
+use bevy::prelude::{Bundle, SpriteBundle};
+
+/// Long enough to trap someone, short enough that a spider can't wall off a floor for good
+const WEB_TURNS: u32 = 40;
+
+#[derive(Bundle)]
+pub struct Web {
+    name: Name,
+    tile_effect: TileEffect,
+    flammable: Flammable,
+    #[bundle]
+    sprite: SpriteBundle,
+}
+
+impl Web {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self {
+            name: Name::new("Web"),
+            tile_effect: TileEffect::new(x, y, TileEffectKind::Web, Some(WEB_TURNS)),
+            flammable: Flammable::new(None),
+            sprite: SpriteBundle::new_overlay("web", x, y),
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use crate::actions::{Action, ActionStack};
+use crate::components::{has_status_effect, Equipment, StatusEffectKind, StatusEffects};
+use crate::input::{CursorTile, LatchedCommands};
+use crate::items::modify_stat;
+use crate::ui::Targeting;
 use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
 use bevy::prelude::{Entity, Query, ResMut, World};
 use dyn_clone::{clone_trait_object, DynClone};
+use serde::Deserialize;
 
+#[derive(Clone)]
 pub struct Actor {
     pub brain: Box<dyn Brain>,
     pub turn_group: TurnGroup,
-    ready_to_act: bool,
+    pub actions_per_turn: u32,
+    actions_remaining: u32,
 }
 
 impl Actor {
@@ -14,9 +22,19 @@ impl Actor {
         Self {
             brain: Box::new(brain),
             turn_group,
-            ready_to_act: false,
+            actions_per_turn: 1,
+            actions_remaining: 0,
         }
     }
+
+    pub fn with_actions_per_turn(mut self, actions_per_turn: u32) -> Self {
+        self.actions_per_turn = actions_per_turn;
+        self
+    }
+
+    fn ready_to_act(&self) -> bool {
+        self.actions_remaining > 0
+    }
 }
 
 clone_trait_object!(Brain);
@@ -26,6 +44,13 @@ pub trait Brain: DynClone + Send + Sync {
         this_entity: Entity,
         world: &mut ImmutableWorld,
     ) -> Option<Box<dyn Action>>;
+
+    /// Called when another actor raises the alarm about where the player was seen
+    /// Function brains can't remember it, so they ignore alarms
+    fn hear_alarm(&mut self, _player_position: IVec2) {}
+
+    /// Called once when the actor's turn group starts its turn, however often it's asked to decide
+    fn start_turn(&mut self) {}
 }
 
 impl<F> Brain for F
@@ -41,7 +66,7 @@ where
     }
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum TurnGroup {
     Player,
     Enemy,
@@ -50,12 +75,17 @@ pub enum TurnGroup {
 
 /// If no more actors left for current turn group
 /// Advance to next turn group
-/// Ready all actors in the new group
-pub fn determine_turn_group(mut turn_group: ResMut<TurnGroup>, mut actors: Query<&mut Actor>) {
+/// Give all actors in the new group their actions for the turn, unless they're slowed and skip it
+/// Their brains are told the turn has started either way
+/// Equipment and haste can add to or take away from an actor's actions, down to one
+pub fn determine_turn_group(
+    mut turn_group: ResMut<TurnGroup>,
+    mut actors: Query<(&mut Actor, Option<&StatusEffects>, Option<&Equipment>)>,
+) {
     let actors = actors.iter_mut().collect::<Vec<_>>();
     let actors_left_for_turn = actors
         .iter()
-        .filter(|actor| actor.turn_group == *turn_group && actor.ready_to_act)
+        .filter(|(actor, _, _)| actor.turn_group == *turn_group && actor.ready_to_act())
         .count();
 
     if actors_left_for_turn == 0 {
@@ -65,9 +95,24 @@ pub fn determine_turn_group(mut turn_group: ResMut<TurnGroup>, mut actors: Query
             TurnGroup::Neutral => TurnGroup::Player,
         };
 
-        for mut actor in actors {
+        for (mut actor, status_effects, equipment) in actors {
             if actor.turn_group == *turn_group {
-                actor.ready_to_act = true;
+                actor.brain.start_turn();
+                let skips_turn = status_effects
+                    .map(|status_effects| status_effects.skips_turn())
+                    .unwrap_or(false);
+                let hasted = status_effects
+                    .map(|status_effects| status_effects.has(StatusEffectKind::Haste))
+                    .unwrap_or(false);
+                let speed = equipment
+                    .map(|equipment| equipment.modifiers().speed)
+                    .unwrap_or(0)
+                    + hasted as i32;
+                actor.actions_remaining = if skips_turn {
+                    0
+                } else {
+                    modify_stat(actor.actions_per_turn, speed, 1)
+                };
             }
         }
     }
@@ -78,25 +123,50 @@ pub fn determine_turn_group(mut turn_group: ResMut<TurnGroup>, mut actors: Query
 /// Stops on the first action being given
 /// If the current turn group is Players, keep asking the first actor each tick until they give one
 /// If the current turn group isn't Players, ask each actor 3 times a tick
+/// Actors with several actions per turn are asked again until they've used them all
+/// Stunned actors lose their remaining actions without being asked
 pub fn decide_next_action(world: &mut World) {
     if !world.get_resource::<ActionStack>().unwrap().is_empty() {
         return;
     }
 
+    let stunned_actors = world
+        .query::<(&Actor, Entity)>()
+        .iter(world)
+        .filter(|(actor, entity)| {
+            actor.ready_to_act() && has_status_effect(world, *entity, StatusEffectKind::Stun)
+        })
+        .map(|(_, entity)| entity)
+        .collect::<Vec<_>>();
+    for entity in stunned_actors {
+        world.get_mut::<Actor>(entity).unwrap().actions_remaining = 0;
+    }
+
     if world.get_resource::<TurnGroup>().unwrap() == &TurnGroup::Player {
         let actor_entity = world
             .query::<(&Actor, Entity)>()
             .iter_mut(world)
-            .filter(|(actor, _)| actor.ready_to_act)
+            .filter(|(actor, _)| actor.ready_to_act())
             .map(|(_, actor_entity)| actor_entity)
             .next();
         if let Some(actor_entity) = actor_entity {
             let mut brain_clone = world.get::<Actor>(actor_entity).unwrap().brain.clone();
             let action = brain_clone.decide_action(actor_entity, &mut ImmutableWorld::new(world));
             world.get_mut::<Actor>(actor_entity).unwrap().brain = brain_clone;
+            // The brain has seen the latched inputs, so they don't carry over to the next decision
+            world.get_resource_mut::<CursorTile>().unwrap().clicked = None;
+            world
+                .get_resource_mut::<LatchedCommands>()
+                .unwrap()
+                .0
+                .clear();
+            world.get_resource_mut::<Targeting>().unwrap().confirmed = None;
 
             if let Some(action) = action {
-                world.get_mut::<Actor>(actor_entity).unwrap().ready_to_act = false;
+                world
+                    .get_mut::<Actor>(actor_entity)
+                    .unwrap()
+                    .actions_remaining -= 1;
                 world.get_resource_mut::<ActionStack>().unwrap().add(action);
             }
         }
@@ -104,7 +174,7 @@ pub fn decide_next_action(world: &mut World) {
         let actor_entities = world
             .query::<(&Actor, Entity)>()
             .iter_mut(world)
-            .filter(|(actor, _)| actor.ready_to_act)
+            .filter(|(actor, _)| actor.ready_to_act())
             .map(|(_, actor_entity)| actor_entity)
             .collect::<Vec<_>>();
         for decision_attempt in 1..=3 {
@@ -115,11 +185,17 @@ pub fn decide_next_action(world: &mut World) {
                 world.get_mut::<Actor>(actor_entity).unwrap().brain = brain_clone;
 
                 if let Some(action) = action {
-                    world.get_mut::<Actor>(actor_entity).unwrap().ready_to_act = false;
+                    world
+                        .get_mut::<Actor>(actor_entity)
+                        .unwrap()
+                        .actions_remaining -= 1;
                     world.get_resource_mut::<ActionStack>().unwrap().add(action);
                     return;
                 } else if decision_attempt == 3 {
-                    world.get_mut::<Actor>(actor_entity).unwrap().ready_to_act = false;
+                    world
+                        .get_mut::<Actor>(actor_entity)
+                        .unwrap()
+                        .actions_remaining = 0;
                 }
             }
         }
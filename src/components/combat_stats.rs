@@ -0,0 +1,28 @@
+use crate::components::equipment_modifiers;
+use crate::items::{modify_stat, StatModifiers};
+use bevy::prelude::{Entity, World};
+
+#[derive(Clone, Copy)]
+pub struct CombatStats {
+    pub attack: u32,
+    pub defense: u32,
+}
+
+impl CombatStats {
+    pub fn new(attack: u32, defense: u32) -> Self {
+        Self { attack, defense }
+    }
+
+    pub fn modified_by(&self, modifiers: StatModifiers) -> Self {
+        Self {
+            attack: modify_stat(self.attack, modifiers.attack, 0),
+            defense: modify_stat(self.defense, modifiers.defense, 0),
+        }
+    }
+}
+
+/// The entity's combat stats with its equipment's modifiers applied
+pub fn derived_combat_stats(world: &World, entity: Entity) -> Option<CombatStats> {
+    let combat_stats = world.get::<CombatStats>(entity)?;
+    Some(combat_stats.modified_by(equipment_modifiers(world, entity)))
+}
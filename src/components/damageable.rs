@@ -1,7 +1,17 @@
-pub struct Damageable {}
+pub struct Damageable {
+    pub health: u32,
+    pub max_health: u32,
+}
 
 impl Damageable {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(max_health: u32) -> Self {
+        Self {
+            health: max_health,
+            max_health,
+        }
+    }
+
+    pub fn is_at_full_health(&self) -> bool {
+        self.health >= self.max_health
     }
 }
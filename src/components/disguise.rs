@@ -0,0 +1,42 @@
+use crate::actions::{ActionStack, RevealAction};
+use crate::components::{Actor, GridPosition, TurnGroup};
+use bevy::prelude::{Changed, Entity, Handle, Query, ResMut, With};
+use bevy::sprite::ColorMaterial;
+
+/// Makes an entity pass for something harmless, without acting, until revealed
+pub struct Disguise {
+    pub true_name: String,
+    pub true_material: Handle<ColorMaterial>,
+    /// Given to the entity once revealed
+    pub actor: Actor,
+}
+
+impl Disguise {
+    pub fn new(true_name: String, true_material: Handle<ColorMaterial>, actor: Actor) -> Self {
+        Self {
+            true_name,
+            true_material,
+            actor,
+        }
+    }
+}
+
+/// Reveals disguised entities the player steps next to
+pub fn reveal_disguises_near_player(
+    mut action_stack: ResMut<ActionStack>,
+    players: Query<(&GridPosition, &Actor), Changed<GridPosition>>,
+    disguised: Query<(&GridPosition, Entity), With<Disguise>>,
+) {
+    for (player_position, actor) in players.iter() {
+        if actor.turn_group != TurnGroup::Player {
+            continue;
+        }
+
+        for (position, entity) in disguised.iter() {
+            let offset = **position - **player_position;
+            if offset.x.abs() + offset.y.abs() == 1 {
+                action_stack.add(Box::new(RevealAction { entity }));
+            }
+        }
+    }
+}
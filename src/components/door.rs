@@ -0,0 +1,33 @@
+use crate::components::Inventory;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+
+/// A door in a room's entrance, which blocks movement and sight while closed
+/// Closed doors have a GridPosition, Terrain and BlocksSight, which are removed while they're open
+pub struct Door {
+    pub position: IVec2,
+    pub open: bool,
+    /// The floor whose key unlocks it, None if it isn't locked
+    pub lock: Option<u32>,
+}
+
+impl Door {
+    /// Whether whoever carries the inventory can open it, which takes the key if it's locked
+    pub fn can_be_opened_with(&self, inventory: Option<&Inventory>) -> bool {
+        match self.lock {
+            Some(floor) => inventory
+                .and_then(|inventory| inventory.key_index(floor))
+                .is_some(),
+            None => true,
+        }
+    }
+}
+
+pub fn closed_door_at(position: IVec2, world: &mut ImmutableWorld) -> Option<Entity> {
+    world
+        .query::<(&Door, Entity)>()
+        .iter(world)
+        .find(|(door, _)| !door.open && door.position == position)
+        .map(|(_, entity)| entity)
+}
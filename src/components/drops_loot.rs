@@ -0,0 +1,2 @@
+/// Drops loot rolled from the archetype's loot table when killed
+pub struct DropsLoot(pub String);
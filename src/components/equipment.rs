@@ -0,0 +1,53 @@
+use crate::components::StatusEffect;
+use crate::items::{EquipmentSlot, Item, ItemKind, StatModifiers};
+use bevy::prelude::{Entity, World};
+use std::collections::HashMap;
+
+/// The items worn in each slot, whose modifiers add to the wearer's stats
+#[derive(Default)]
+pub struct Equipment {
+    slots: HashMap<EquipmentSlot, Item>,
+}
+
+impl Equipment {
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&Item> {
+        self.slots.get(&slot)
+    }
+
+    /// Puts the item in its slot, returning whatever was there before
+    /// Items that aren't equipment are given straight back
+    pub fn equip(&mut self, item: Item) -> Option<Item> {
+        match item.kind {
+            ItemKind::Equipment { slot, .. } => self.slots.insert(slot, item),
+            _ => Some(item),
+        }
+    }
+
+    pub fn unequip(&mut self, slot: EquipmentSlot) -> Option<Item> {
+        self.slots.remove(&slot)
+    }
+
+    /// The modifiers of everything equipped, including their affixes, added together
+    pub fn modifiers(&self) -> StatModifiers {
+        self.slots
+            .values()
+            .fold(StatModifiers::default(), |total, item| {
+                total + item.modifiers()
+            })
+    }
+
+    /// The status effects the affixes of everything equipped apply to whatever the wearer hits
+    pub fn on_hit_effects(&self) -> Vec<StatusEffect> {
+        self.slots
+            .values()
+            .flat_map(|item| item.on_hit_effects())
+            .collect()
+    }
+}
+
+pub fn equipment_modifiers(world: &World, entity: Entity) -> StatModifiers {
+    world
+        .get::<Equipment>(entity)
+        .map(|equipment| equipment.modifiers())
+        .unwrap_or_default()
+}
@@ -0,0 +1,11 @@
+/// Explodes when the entity dies
+pub struct Explosive {
+    pub radius: i32,
+    pub damage: u32,
+}
+
+impl Explosive {
+    pub fn new(radius: i32, damage: u32) -> Self {
+        Self { radius, damage }
+    }
+}
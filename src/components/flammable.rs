@@ -0,0 +1,10 @@
+pub struct Flammable {
+    /// What the entity looks like once burnt, or None if fire destroys it
+    pub burnt_sprite: Option<&'static str>,
+}
+
+impl Flammable {
+    pub fn new(burnt_sprite: Option<&'static str>) -> Self {
+        Self { burnt_sprite }
+    }
+}
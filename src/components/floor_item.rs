@@ -0,0 +1,8 @@
+use crate::items::ItemStack;
+use bevy::math::IVec2;
+
+/// A stack of items lying on a tile, which doesn't block movement unlike a GridPosition
+pub struct FloorItem {
+    pub position: IVec2,
+    pub stack: ItemStack,
+}
@@ -0,0 +1,4 @@
+use crate::components::StatusEffect;
+
+/// Applies a status effect to whatever the entity hits in melee
+pub struct InflictsOnHit(pub StatusEffect);
@@ -0,0 +1,166 @@
+use crate::items::{Item, ItemKind, ItemStack};
+
+/// What an entity carries, as stacks of items in a limited number of slots
+pub struct Inventory {
+    pub capacity: usize,
+    pub stacks: Vec<ItemStack>,
+    /// Which stack the player's item commands act on
+    pub selected: usize,
+}
+
+impl Inventory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            stacks: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    /// Whether at least one more of the item would fit
+    pub fn has_room_for(&self, item: &Item) -> bool {
+        self.stacks.len() < self.capacity
+            || self
+                .stacks
+                .iter()
+                .any(|stack| stack.item == *item && stack.count < item.max_stack)
+    }
+
+    /// Tops up existing stacks of the item first, then fills empty slots
+    /// Returns whatever didn't fit
+    pub fn add(&mut self, mut stack: ItemStack) -> Option<ItemStack> {
+        for existing in self.stacks.iter_mut() {
+            if existing.item != stack.item {
+                continue;
+            }
+            let room = stack.item.max_stack.saturating_sub(existing.count);
+            let moved = stack.count.min(room);
+            existing.count += moved;
+            stack.count -= moved;
+        }
+        while stack.count > 0 && self.stacks.len() < self.capacity {
+            let moved = stack.count.min(stack.item.max_stack);
+            self.stacks.push(ItemStack::new(stack.item.clone(), moved));
+            stack.count -= moved;
+        }
+
+        if stack.count > 0 {
+            Some(stack)
+        } else {
+            None
+        }
+    }
+
+    /// Removes the whole stack at the index
+    pub fn take(&mut self, index: usize) -> Option<ItemStack> {
+        if index >= self.stacks.len() {
+            return None;
+        }
+        let stack = self.stacks.remove(index);
+        if self.selected >= self.stacks.len() {
+            self.selected = 0;
+        }
+        Some(stack)
+    }
+
+    /// Removes a single item from the stack at the index
+    pub fn take_one(&mut self, index: usize) -> Option<Item> {
+        let stack = self.stacks.get_mut(index)?;
+        stack.count -= 1;
+        let item = stack.item.clone();
+        if stack.count == 0 {
+            self.take(index);
+        }
+        Some(item)
+    }
+
+    /// Where the key to the floor is carried, if it is
+    pub fn key_index(&self, floor: u32) -> Option<usize> {
+        self.stacks
+            .iter()
+            .position(|stack| stack.item.kind == ItemKind::Key { floor })
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = if self.stacks.is_empty() {
+            0
+        } else {
+            (self.selected + 1) % self.stacks.len()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{short_sword, throwing_knife};
+
+    #[test]
+    fn add_tops_up_existing_stacks_first() {
+        let mut inventory = Inventory::new(4);
+        assert!(inventory
+            .add(ItemStack::new(throwing_knife(), 10))
+            .is_none());
+        assert!(inventory.add(ItemStack::new(throwing_knife(), 5)).is_none());
+        let counts = inventory
+            .stacks
+            .iter()
+            .map(|stack| stack.count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![12, 3]);
+    }
+
+    #[test]
+    fn add_splits_into_full_stacks() {
+        let mut inventory = Inventory::new(4);
+        assert!(inventory
+            .add(ItemStack::new(throwing_knife(), 30))
+            .is_none());
+        let counts = inventory
+            .stacks
+            .iter()
+            .map(|stack| stack.count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![12, 12, 6]);
+    }
+
+    #[test]
+    fn add_returns_what_doesnt_fit() {
+        let mut inventory = Inventory::new(1);
+        let leftover = inventory.add(ItemStack::new(throwing_knife(), 20)).unwrap();
+        assert_eq!(leftover.count, 8);
+        assert_eq!(inventory.stacks[0].count, 12);
+
+        let leftover = inventory.add(ItemStack::new(short_sword(), 1)).unwrap();
+        assert_eq!(leftover.count, 1);
+        assert_eq!(inventory.stacks.len(), 1);
+    }
+
+    #[test]
+    fn different_items_dont_stack() {
+        let mut inventory = Inventory::new(4);
+        inventory.add(ItemStack::new(short_sword(), 1));
+        inventory.add(ItemStack::new(short_sword(), 1));
+        assert_eq!(inventory.stacks.len(), 2);
+    }
+
+    #[test]
+    fn full_inventory_has_room_only_in_unfilled_stacks() {
+        let mut inventory = Inventory::new(1);
+        assert!(inventory.has_room_for(&short_sword()));
+        inventory.add(ItemStack::new(throwing_knife(), 11));
+        assert!(inventory.has_room_for(&throwing_knife()));
+        assert!(!inventory.has_room_for(&short_sword()));
+        inventory.add(ItemStack::new(throwing_knife(), 1));
+        assert!(!inventory.has_room_for(&throwing_knife()));
+    }
+
+    #[test]
+    fn taking_the_last_of_a_stack_removes_it() {
+        let mut inventory = Inventory::new(4);
+        inventory.add(ItemStack::new(throwing_knife(), 1));
+        assert_eq!(inventory.take_one(0), Some(throwing_knife()));
+        assert!(inventory.stacks.is_empty());
+        assert_eq!(inventory.take_one(0), None);
+    }
+}
@@ -1,9 +1,43 @@
 mod actor;
+mod combat_stats;
 mod damageable;
+mod disguise;
+mod door;
+mod drops_loot;
+mod equipment;
+mod explosive;
+mod flammable;
+mod floor_item;
 mod grid_position;
+mod inflicts_on_hit;
+mod inventory;
 mod keep_between_floors;
+mod natural_regeneration;
+mod passability;
+mod splitting;
+mod status_effects;
+mod tile_effect;
+mod toxic_trail;
+mod viewshed;
 
 pub use actor::*;
+pub use combat_stats::*;
 pub use damageable::*;
+pub use disguise::*;
+pub use door::*;
+pub use drops_loot::*;
+pub use equipment::*;
+pub use explosive::*;
+pub use flammable::*;
+pub use floor_item::*;
 pub use grid_position::*;
+pub use inflicts_on_hit::*;
+pub use inventory::*;
 pub use keep_between_floors::*;
+pub use natural_regeneration::*;
+pub use passability::*;
+pub use splitting::*;
+pub use status_effects::*;
+pub use tile_effect::*;
+pub use toxic_trail::*;
+pub use viewshed::*;
@@ -0,0 +1,40 @@
+use crate::components::{Actor, Damageable, TurnGroup};
+use bevy::prelude::{Query, Res};
+use std::num::NonZeroU32;
+
+/// Slowly heals an actor as turns pass
+pub struct NaturalRegeneration {
+    pub turns_per_health: NonZeroU32,
+    turns_until_next_health: u32,
+}
+
+impl NaturalRegeneration {
+    pub fn new(turns_per_health: NonZeroU32) -> Self {
+        Self {
+            turns_per_health,
+            turns_until_next_health: turns_per_health.get(),
+        }
+    }
+}
+
+/// Ticks regeneration for every actor whose turn group just started its turn
+pub fn regenerate_health(
+    turn_group: Res<TurnGroup>,
+    mut actors: Query<(&Actor, &mut NaturalRegeneration, &mut Damageable)>,
+) {
+    if !turn_group.is_changed() {
+        return;
+    }
+
+    for (actor, mut regeneration, mut damageable) in actors.iter_mut() {
+        if actor.turn_group != *turn_group || damageable.is_at_full_health() {
+            continue;
+        }
+
+        regeneration.turns_until_next_health -= 1;
+        if regeneration.turns_until_next_health == 0 {
+            damageable.health += 1;
+            regeneration.turns_until_next_health = regeneration.turns_per_health.get();
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// Marks walls and other level geometry, as opposed to creatures
+pub struct Terrain;
+
+/// Lets an entity move into a terrain tile, as long as it isn't already inside one
+pub struct Phasing;
+
+/// Lets an entity walk through webs without getting stuck
+pub struct WebWalker;
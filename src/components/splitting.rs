@@ -0,0 +1,29 @@
+/// Splits in two when hit hard enough, until it's too small to split again
+pub struct Splitting {
+    pub size: u32,
+    /// The size it spawned at
+    pub max_size: u32,
+    pub min_size: u32,
+    /// The least damage a single blow has to deal to split it
+    pub damage_threshold: u32,
+}
+
+impl Splitting {
+    pub fn new(size: u32, min_size: u32, damage_threshold: u32) -> Self {
+        Self {
+            size,
+            max_size: size,
+            min_size,
+            damage_threshold,
+        }
+    }
+
+    pub fn can_split(&self) -> bool {
+        self.size > self.min_size
+    }
+
+    /// How large to draw it, relative to its full size
+    pub fn sprite_scale(&self) -> f32 {
+        (self.size + self.max_size) as f32 / (2 * self.max_size) as f32
+    }
+}
@@ -0,0 +1,146 @@
+use crate::actions::{ActionStack, DamageAction};
+use crate::components::{Actor, Damageable, TurnGroup};
+use bevy::prelude::{Entity, Query, Res, ResMut, World};
+use serde::Deserialize;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+pub enum StatusEffectKind {
+    /// Deals its magnitude in damage each turn, stacking intensifies it
+    Poison,
+    /// Deals its magnitude in damage each turn
+    Burning,
+    /// Loses its turns
+    Stun,
+    /// Loses every other turn
+    Slow,
+    /// Gains an extra action every turn
+    Haste,
+    /// Heals its magnitude each turn
+    Regeneration,
+    /// Can't move, but can still act in place
+    /// Struggling makes it wear off faster, see StruggleAction
+    Immobilized,
+}
+
+impl StatusEffectKind {
+    /// Whether applying the effect again adds to its magnitude, rather than keeping the strongest
+    fn intensifies(self) -> bool {
+        matches!(self, StatusEffectKind::Poison)
+    }
+}
+
+#[derive(Clone, Copy, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    /// How many of the affected entity's turns it lasts
+    pub turns_remaining: u32,
+    #[serde(default)]
+    pub magnitude: u32,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusEffectKind, turns: u32, magnitude: u32) -> Self {
+        Self {
+            kind,
+            turns_remaining: turns,
+            magnitude,
+        }
+    }
+}
+
+/// Every status effect currently on an entity, at most one of each kind
+#[derive(Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Reapplying an effect refreshes its duration to the longer of the two
+    pub fn add(&mut self, effect: StatusEffect) {
+        match self
+            .effects
+            .iter_mut()
+            .find(|existing| existing.kind == effect.kind)
+        {
+            Some(existing) => {
+                existing.turns_remaining = existing.turns_remaining.max(effect.turns_remaining);
+                existing.magnitude = if effect.kind.intensifies() {
+                    existing.magnitude + effect.magnitude
+                } else {
+                    existing.magnitude.max(effect.magnitude)
+                };
+            }
+            None => self.effects.push(effect),
+        }
+    }
+
+    pub fn get_mut(&mut self, kind: StatusEffectKind) -> Option<&mut StatusEffect> {
+        self.effects.iter_mut().find(|effect| effect.kind == kind)
+    }
+
+    pub fn remove(&mut self, kind: StatusEffectKind) {
+        self.effects.retain(|effect| effect.kind != kind);
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    /// Slowed entities lose every other turn, the last one included
+    /// Counts on being checked before tick_status_effects counts the turn down, see main
+    pub fn skips_turn(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| effect.kind == StatusEffectKind::Slow && effect.turns_remaining % 2 == 1)
+    }
+}
+
+pub fn has_status_effect(world: &World, entity: Entity, kind: StatusEffectKind) -> bool {
+    world
+        .get::<StatusEffects>(entity)
+        .map(|status_effects| status_effects.has(kind))
+        .unwrap_or(false)
+}
+
+/// At the start of each turn group's turn, applies the effects on its actors and counts them down
+/// An effect stays for the whole of its last turn, and is removed at the start of the next
+pub fn tick_status_effects(
+    turn_group: Res<TurnGroup>,
+    mut action_stack: ResMut<ActionStack>,
+    mut actors: Query<(&Actor, &mut StatusEffects, Option<&mut Damageable>, Entity)>,
+) {
+    if !turn_group.is_changed() {
+        return;
+    }
+
+    for (actor, mut status_effects, mut damageable, entity) in actors.iter_mut() {
+        if actor.turn_group != *turn_group {
+            continue;
+        }
+
+        status_effects
+            .effects
+            .retain(|effect| effect.turns_remaining > 0);
+        for effect in status_effects.effects.iter_mut() {
+            match effect.kind {
+                StatusEffectKind::Poison | StatusEffectKind::Burning => {
+                    action_stack.add(Box::new(DamageAction {
+                        target: entity,
+                        amount: effect.magnitude,
+                    }));
+                }
+                StatusEffectKind::Regeneration => {
+                    if let Some(damageable) = &mut damageable {
+                        damageable.health =
+                            (damageable.health + effect.magnitude).min(damageable.max_health);
+                    }
+                }
+                StatusEffectKind::Stun
+                | StatusEffectKind::Slow
+                | StatusEffectKind::Haste
+                | StatusEffectKind::Immobilized => {}
+            }
+            effect.turns_remaining -= 1;
+        }
+    }
+}
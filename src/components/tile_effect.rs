@@ -0,0 +1,91 @@
+use crate::actions::{ActionStack, ApplyStatusEffectAction, DamageAction};
+use crate::components::{
+    Damageable, GridPosition, StatusEffect, StatusEffectKind, ToxicTrail, TurnGroup,
+};
+use bevy::math::IVec2;
+use bevy::prelude::{Commands, Entity, Query, Res, ResMut, With};
+
+/// Something lying on a tile that doesn't block movement, unlike a GridPosition
+pub struct TileEffect {
+    pub position: IVec2,
+    pub kind: TileEffectKind,
+    /// None for effects that last until removed
+    pub turns_remaining: Option<u32>,
+}
+
+impl TileEffect {
+    pub fn new(x: i32, y: i32, kind: TileEffectKind, turns_remaining: Option<u32>) -> Self {
+        Self {
+            position: IVec2::new(x, y),
+            kind,
+            turns_remaining,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TileEffectKind {
+    /// Hurts whatever stands in it and sets it burning
+    Fire,
+    /// Sticks whatever walks into it in place, see MoveAction
+    Web,
+    /// Poisons whatever stands in it, unless it leaves a toxic trail itself
+    Toxic,
+    /// Lets the player descend to the next floor, see DescendAction
+    Stairs,
+}
+
+const FIRE_DAMAGE: u32 = 1;
+const BURNING_TURNS: u32 = 2;
+const POISON_TURNS: u32 = 3;
+
+/// At the start of the Neutral group's turn, applies every tile effect to whatever is on its tile
+/// and removes the effects that have run out
+pub fn tick_tile_effects(
+    mut commands: Commands,
+    turn_group: Res<TurnGroup>,
+    mut action_stack: ResMut<ActionStack>,
+    mut tile_effects: Query<(&mut TileEffect, Entity)>,
+    damageables: Query<(&GridPosition, Option<&ToxicTrail>, Entity), With<Damageable>>,
+) {
+    if !turn_group.is_changed() || *turn_group != TurnGroup::Neutral {
+        return;
+    }
+
+    for (mut tile_effect, tile_effect_entity) in tile_effects.iter_mut() {
+        match tile_effect.kind {
+            TileEffectKind::Fire => {
+                for (position, _, entity) in damageables.iter() {
+                    if **position == tile_effect.position {
+                        action_stack.add(Box::new(ApplyStatusEffectAction {
+                            target: entity,
+                            effect: StatusEffect::new(StatusEffectKind::Burning, BURNING_TURNS, 1),
+                        }));
+                        action_stack.add(Box::new(DamageAction {
+                            target: entity,
+                            amount: FIRE_DAMAGE,
+                        }));
+                    }
+                }
+            }
+            TileEffectKind::Web | TileEffectKind::Stairs => {}
+            TileEffectKind::Toxic => {
+                for (position, toxic_trail, entity) in damageables.iter() {
+                    if **position == tile_effect.position && toxic_trail.is_none() {
+                        action_stack.add(Box::new(ApplyStatusEffectAction {
+                            target: entity,
+                            effect: StatusEffect::new(StatusEffectKind::Poison, POISON_TURNS, 1),
+                        }));
+                    }
+                }
+            }
+        }
+
+        if let Some(turns_remaining) = &mut tile_effect.turns_remaining {
+            *turns_remaining = turns_remaining.saturating_sub(1);
+            if *turns_remaining == 0 {
+                commands.entity(tile_effect_entity).despawn();
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// Leaves a toxic puddle on every tile the entity moves off of
+/// Entities leaving a trail are unharmed by toxic puddles
+pub struct ToxicTrail {
+    pub puddle_turns: u32,
+}
+
+impl ToxicTrail {
+    pub fn new(puddle_turns: u32) -> Self {
+        Self { puddle_turns }
+    }
+}
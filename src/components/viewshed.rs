@@ -0,0 +1,62 @@
+use crate::components::{Actor, Equipment, GridPosition, TurnGroup};
+use crate::dungeon::DungeonLayout;
+use bevy::math::IVec2;
+use bevy::prelude::{Query, ResMut, With};
+use std::collections::HashSet;
+
+/// Stops sight at the entity's tile like a wall would, see update_viewsheds
+pub struct BlocksSight;
+
+pub struct Viewshed {
+    pub radius: i32,
+    pub visible_positions: HashSet<IVec2>,
+}
+
+impl Viewshed {
+    pub fn new(radius: i32) -> Self {
+        Self {
+            radius,
+            visible_positions: HashSet::new(),
+        }
+    }
+
+    pub fn can_see(&self, position: IVec2) -> bool {
+        self.visible_positions.contains(&position)
+    }
+}
+
+/// Recalculates what every viewer can see
+/// Tiles seen by the player are marked as explored
+/// Equipment can widen or narrow the radius, down to one tile
+/// Nothing can be seen past whatever blocks sight, like a closed door
+pub fn update_viewsheds(
+    mut dungeon_layout: ResMut<DungeonLayout>,
+    sight_blockers: Query<&GridPosition, With<BlocksSight>>,
+    mut viewers: Query<(
+        &GridPosition,
+        &mut Viewshed,
+        Option<&Actor>,
+        Option<&Equipment>,
+    )>,
+) {
+    let blocked_positions = sight_blockers
+        .iter()
+        .map(|position| **position)
+        .collect::<HashSet<_>>();
+    for (position, mut viewshed, actor, equipment) in viewers.iter_mut() {
+        let sight_bonus = equipment
+            .map(|equipment| equipment.modifiers().sight_radius)
+            .unwrap_or(0);
+        let radius = (viewshed.radius + sight_bonus).max(1);
+        viewshed.visible_positions =
+            dungeon_layout.visible_positions_with(**position, radius, |position| {
+                blocked_positions.contains(&position)
+            });
+
+        if actor.map(|actor| actor.turn_group == TurnGroup::Player) == Some(true) {
+            dungeon_layout
+                .explored_positions
+                .extend(viewshed.visible_positions.iter().copied());
+        }
+    }
+}
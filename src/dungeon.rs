@@ -0,0 +1,202 @@
+use crate::actions::Direction;
+use bevy::math::IVec2;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+const RUN_SEED_VARIABLE: &str = "DUNGEON_HEART_SEED";
+
+/// Seeds whatever should stay the same for a whole run, like which potion is which
+/// Set DUNGEON_HEART_SEED to replay a seed, otherwise a random one is picked and logged at startup
+pub fn run_seed() -> u64 {
+    std::env::var(RUN_SEED_VARIABLE)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or_else(rand::random)
+}
+
+/// The seed the whole run was started with
+pub struct RunSeed(pub u64);
+
+impl RunSeed {
+    /// Rolls everything generated for the floor at the depth, from its layout to its loot
+    /// Each floor gets its own stream, so a seed always generates the same floors
+    /// however the run has gone so far
+    pub fn floor_rng(&self, depth: u32) -> StdRng {
+        StdRng::seed_from_u64(self.0 ^ (depth as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15))
+    }
+}
+
+/// Rolls whatever happens during play, like what monsters drop
+/// Kept apart from the floors' streams so play can't change what later floors look like
+pub struct RunRng(pub StdRng);
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+/// How many floors down the current floor is, starting from 1
+pub struct DungeonDepth(pub u32);
+
+/// The static layout of the current floor, and what the player has seen of it
+pub struct DungeonLayout {
+    pub floor_positions: HashSet<IVec2>,
+    pub wall_positions: HashSet<IVec2>,
+    pub room_centers: Vec<IVec2>,
+    pub explored_positions: HashSet<IVec2>,
+}
+
+impl DungeonLayout {
+    pub fn new(
+        floor_positions: HashSet<IVec2>,
+        wall_positions: HashSet<IVec2>,
+        room_centers: Vec<IVec2>,
+    ) -> Self {
+        Self {
+            floor_positions,
+            wall_positions,
+            room_centers,
+            explored_positions: HashSet::new(),
+        }
+    }
+
+    pub fn is_floor(&self, position: IVec2) -> bool {
+        self.floor_positions.contains(&position)
+    }
+
+    pub fn is_explored(&self, position: IVec2) -> bool {
+        self.explored_positions.contains(&position)
+    }
+
+    /// Casts rays from the origin to every tile on the edge of the radius
+    /// Rays stop at (but include) the first tile that isn't a floor
+    pub fn visible_positions(&self, origin: IVec2, radius: i32) -> HashSet<IVec2> {
+        self.visible_positions_with(origin, radius, |_| false)
+    }
+
+    /// Like visible_positions, but rays also stop at floor tiles for which blocks_sight returns true
+    pub fn visible_positions_with<F>(
+        &self,
+        origin: IVec2,
+        radius: i32,
+        blocks_sight: F,
+    ) -> HashSet<IVec2>
+    where
+        F: Fn(IVec2) -> bool,
+    {
+        let mut visible_positions = HashSet::new();
+        visible_positions.insert(origin);
+
+        let mut edge = Vec::new();
+        for i in -radius..=radius {
+            edge.push(origin + IVec2::new(i, radius));
+            edge.push(origin + IVec2::new(i, -radius));
+            edge.push(origin + IVec2::new(radius, i));
+            edge.push(origin + IVec2::new(-radius, i));
+        }
+
+        for target in edge {
+            for position in line(origin, target).into_iter().skip(1) {
+                let offset = position - origin;
+                if offset.x * offset.x + offset.y * offset.y > radius * radius {
+                    break;
+                }
+                visible_positions.insert(position);
+                if !self.is_floor(position) || blocks_sight(position) {
+                    break;
+                }
+            }
+        }
+
+        visible_positions
+    }
+
+    /// Breadth first search over floor tiles for which is_passable returns true
+    /// The returned path excludes the start and includes the goal
+    pub fn find_path<F>(&self, start: IVec2, goal: IVec2, is_passable: F) -> Option<Vec<IVec2>>
+    where
+        F: Fn(IVec2) -> bool,
+    {
+        self.find_path_to_nearest(start, |position| position == goal, is_passable)
+    }
+
+    /// Like find_path, but to the closest position for which is_goal returns true
+    pub fn find_path_to_nearest<G, F>(
+        &self,
+        start: IVec2,
+        is_goal: G,
+        is_passable: F,
+    ) -> Option<Vec<IVec2>>
+    where
+        G: Fn(IVec2) -> bool,
+        F: Fn(IVec2) -> bool,
+    {
+        let mut came_from = HashMap::new();
+        let mut frontier = VecDeque::new();
+        frontier.push_back(start);
+        came_from.insert(start, start);
+
+        while let Some(position) = frontier.pop_front() {
+            if is_goal(position) {
+                let mut path = Vec::new();
+                let mut current = position;
+                while current != start {
+                    path.push(current);
+                    current = came_from[&current];
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for direction in Direction::ALL {
+                let neighbor = position + direction.offset();
+                if !came_from.contains_key(&neighbor)
+                    && self.is_floor(neighbor)
+                    && is_passable(neighbor)
+                {
+                    came_from.insert(neighbor, position);
+                    frontier.push_back(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// An explored floor tile next to an unexplored one
+    pub fn is_frontier(&self, position: IVec2) -> bool {
+        self.is_floor(position)
+            && self.is_explored(position)
+            && Direction::ALL
+                .iter()
+                .any(|direction| !self.is_explored(position + direction.offset()))
+    }
+}
+
+/// Bresenham's line algorithm, including both endpoints
+pub fn line(start: IVec2, end: IVec2) -> Vec<IVec2> {
+    let mut positions = Vec::new();
+    let dx = (end.x - start.x).abs();
+    let dy = -(end.y - start.y).abs();
+    let step_x = if start.x < end.x { 1 } else { -1 };
+    let step_y = if start.y < end.y { 1 } else { -1 };
+    let mut error = dx + dy;
+    let mut current = start;
+    loop {
+        positions.push(current);
+        if current == end {
+            return positions;
+        }
+        let doubled_error = 2 * error;
+        if doubled_error >= dy {
+            error += dy;
+            current.x += step_x;
+        }
+        if doubled_error <= dx {
+            error += dx;
+            current.y += step_y;
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::input::load_config_or_default;
+use crate::items::ItemCategory;
+use serde::{Deserialize, Serialize};
+
+const AUTO_EXPLORE_CONFIG_PATH: &str = "auto_explore.ron";
+
+#[derive(Serialize, Deserialize)]
+pub struct AutoExploreConfig {
+    /// Items of these categories are picked up while exploring, the rest are left lying
+    pub pick_up: Vec<ItemCategory>,
+}
+
+impl AutoExploreConfig {
+    /// Loads the config from auto_explore.ron in the working directory
+    pub fn load() -> Self {
+        load_config_or_default(AUTO_EXPLORE_CONFIG_PATH)
+    }
+}
+
+impl Default for AutoExploreConfig {
+    fn default() -> Self {
+        Self {
+            pick_up: vec![
+                ItemCategory::Misc,
+                ItemCategory::Equipment,
+                ItemCategory::Consumable,
+                ItemCategory::Key,
+            ],
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::log::warn;
+use serde::de::DeserializeOwned;
+use std::fs;
+
+/// Loads a RON config file from the working directory
+/// Falls back to the defaults if the file is missing or invalid
+pub fn load_config_or_default<T: DeserializeOwned + Default>(path: &str) -> T {
+    let file = match fs::read_to_string(path) {
+        Ok(file) => file,
+        Err(_) => return T::default(),
+    };
+    match ron::from_str(&file) {
+        Ok(config) => config,
+        Err(error) => {
+            warn!("Failed to parse {}: {}", path, error);
+            T::default()
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::input::Input;
+use bevy::math::{IVec2, Vec2};
+use bevy::prelude::{GlobalTransform, MouseButton, Query, Res, ResMut};
+use bevy::render::camera::OrthographicProjection;
+use bevy::window::Windows;
+
+/// The grid position under the mouse cursor
+/// clicked is kept from a left mouse button press until the player's turn consumes it
+pub struct CursorTile {
+    pub hovered: Option<IVec2>,
+    pub clicked: Option<IVec2>,
+}
+
+impl CursorTile {
+    pub fn new() -> Self {
+        Self {
+            hovered: None,
+            clicked: None,
+        }
+    }
+}
+
+/// Converts the cursor's window position to a grid position through the camera
+pub fn update_cursor_tile(
+    windows: Res<Windows>,
+    mouse: Res<Input<MouseButton>>,
+    camera: Query<(&OrthographicProjection, &GlobalTransform)>,
+    mut cursor_tile: ResMut<CursorTile>,
+) {
+    let cursor_position = windows
+        .get_primary()
+        .and_then(|window| window.cursor_position());
+    cursor_tile.hovered = match (cursor_position, camera.iter().next()) {
+        (Some(cursor_position), Some((projection, transform))) => {
+            let world_position = Vec2::new(
+                projection.left + cursor_position.x,
+                projection.bottom + cursor_position.y,
+            ) * projection.scale
+                + transform.translation.truncate();
+            let grid_position = (world_position / 32.0).round();
+            Some(IVec2::new(grid_position.x as i32, grid_position.y as i32))
+        }
+        _ => None,
+    };
+    if mouse.just_pressed(MouseButton::Left) && cursor_tile.hovered.is_some() {
+        cursor_tile.clicked = cursor_tile.hovered;
+    }
+}
@@ -0,0 +1,108 @@
+use crate::actions::Direction;
+use crate::input::{load_config_or_default, PlayerCommand};
+use bevy::app::EventReader;
+use bevy::input::gamepad::{Gamepad, GamepadButtonType, GamepadEvent, GamepadEventType};
+use bevy::prelude::ResMut;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const GAMEPAD_BINDINGS_PATH: &str = "gamepad_bindings.ron";
+
+#[derive(Serialize, Deserialize)]
+pub struct GamepadBindings {
+    pub buttons: Vec<ButtonBinding>,
+    /// How far the left stick or an analog D-pad must be pushed before it counts as a move
+    pub stick_deadzone: f32,
+}
+
+/// All buttons must be held at once for the command to trigger
+#[derive(Serialize, Deserialize)]
+pub struct ButtonBinding {
+    pub buttons: Vec<GamepadButtonType>,
+    pub command: PlayerCommand,
+}
+
+impl GamepadBindings {
+    /// Loads bindings from gamepad_bindings.ron in the working directory
+    pub fn load() -> Self {
+        load_config_or_default(GAMEPAD_BINDINGS_PATH)
+    }
+
+    fn bind(&mut self, buttons: &[GamepadButtonType], command: PlayerCommand) {
+        self.buttons.push(ButtonBinding {
+            buttons: buttons.to_vec(),
+            command,
+        });
+    }
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        let mut gamepad_bindings = Self {
+            buttons: Vec::new(),
+            stick_deadzone: 0.5,
+        };
+
+        gamepad_bindings.bind(
+            &[GamepadButtonType::DPadUp],
+            PlayerCommand::Move(Direction::Up),
+        );
+        gamepad_bindings.bind(
+            &[GamepadButtonType::DPadLeft],
+            PlayerCommand::Move(Direction::Left),
+        );
+        gamepad_bindings.bind(
+            &[GamepadButtonType::DPadDown],
+            PlayerCommand::Move(Direction::Down),
+        );
+        gamepad_bindings.bind(
+            &[GamepadButtonType::DPadRight],
+            PlayerCommand::Move(Direction::Right),
+        );
+        gamepad_bindings.bind(&[GamepadButtonType::North], PlayerCommand::Wait);
+        gamepad_bindings.bind(&[GamepadButtonType::South], PlayerCommand::PickUp);
+        gamepad_bindings.bind(&[GamepadButtonType::LeftThumb], PlayerCommand::Drop);
+        gamepad_bindings.bind(&[GamepadButtonType::LeftTrigger2], PlayerCommand::NextItem);
+        gamepad_bindings.bind(&[GamepadButtonType::West], PlayerCommand::Use);
+        gamepad_bindings.bind(&[GamepadButtonType::RightTrigger2], PlayerCommand::Fire);
+        gamepad_bindings.bind(&[GamepadButtonType::RightThumb], PlayerCommand::NextTarget);
+        gamepad_bindings.bind(&[GamepadButtonType::Start], PlayerCommand::Confirm);
+        gamepad_bindings.bind(&[GamepadButtonType::Select], PlayerCommand::Cancel);
+        // The left shoulder isn't bound on its own, it's only held as a modifier for the face buttons
+        gamepad_bindings.bind(
+            &[GamepadButtonType::LeftTrigger, GamepadButtonType::West],
+            PlayerCommand::Throw,
+        );
+        gamepad_bindings.bind(
+            &[GamepadButtonType::LeftTrigger, GamepadButtonType::East],
+            PlayerCommand::Rest,
+        );
+        gamepad_bindings.bind(
+            &[GamepadButtonType::LeftTrigger, GamepadButtonType::North],
+            PlayerCommand::CloseDoor,
+        );
+        gamepad_bindings.bind(&[GamepadButtonType::East], PlayerCommand::AutoExplore);
+        gamepad_bindings.bind(&[GamepadButtonType::RightTrigger], PlayerCommand::Descend);
+
+        gamepad_bindings
+    }
+}
+
+pub struct ConnectedGamepads(pub HashSet<Gamepad>);
+
+pub fn track_connected_gamepads(
+    mut gamepad_events: EventReader<GamepadEvent>,
+    mut connected_gamepads: ResMut<ConnectedGamepads>,
+) {
+    for GamepadEvent(gamepad, event_type) in gamepad_events.iter() {
+        match event_type {
+            GamepadEventType::Connected => {
+                connected_gamepads.0.insert(*gamepad);
+            }
+            GamepadEventType::Disconnected => {
+                connected_gamepads.0.remove(gamepad);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::actions::Direction;
+use crate::input::{load_config_or_default, PlayerCommand};
+use crate::items::EquipmentSlot;
+use bevy::prelude::KeyCode;
+use serde::{Deserialize, Serialize};
+
+const KEY_BINDINGS_PATH: &str = "key_bindings.ron";
+
+#[derive(Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub bindings: Vec<KeyBinding>,
+}
+
+/// All keys must be held at once for the command to trigger
+#[derive(Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub keys: Vec<KeyCode>,
+    pub command: PlayerCommand,
+}
+
+impl KeyBindings {
+    /// Loads bindings from key_bindings.ron in the working directory
+    pub fn load() -> Self {
+        load_config_or_default(KEY_BINDINGS_PATH)
+    }
+
+    fn bind(&mut self, keys: &[KeyCode], command: PlayerCommand) {
+        self.bindings.push(KeyBinding {
+            keys: keys.to_vec(),
+            command,
+        });
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let mut key_bindings = Self {
+            bindings: Vec::new(),
+        };
+
+        for (keys, direction) in [
+            // WASD
+            ([KeyCode::W], Direction::Up),
+            ([KeyCode::A], Direction::Left),
+            ([KeyCode::S], Direction::Down),
+            ([KeyCode::D], Direction::Right),
+            // Arrows
+            ([KeyCode::Up], Direction::Up),
+            ([KeyCode::Left], Direction::Left),
+            ([KeyCode::Down], Direction::Down),
+            ([KeyCode::Right], Direction::Right),
+            // Numpad
+            ([KeyCode::Numpad8], Direction::Up),
+            ([KeyCode::Numpad4], Direction::Left),
+            ([KeyCode::Numpad2], Direction::Down),
+            ([KeyCode::Numpad6], Direction::Right),
+            // Vi-keys
+            ([KeyCode::K], Direction::Up),
+            ([KeyCode::H], Direction::Left),
+            ([KeyCode::J], Direction::Down),
+            ([KeyCode::L], Direction::Right),
+        ] {
+            key_bindings.bind(&keys, PlayerCommand::Move(direction));
+        }
+
+        key_bindings.bind(&[KeyCode::Space], PlayerCommand::Wait);
+        key_bindings.bind(&[KeyCode::Period], PlayerCommand::Wait);
+        key_bindings.bind(&[KeyCode::Numpad5], PlayerCommand::Wait);
+        key_bindings.bind(&[KeyCode::R], PlayerCommand::Rest);
+        key_bindings.bind(&[KeyCode::G], PlayerCommand::PickUp);
+        key_bindings.bind(&[KeyCode::Comma], PlayerCommand::PickUp);
+        key_bindings.bind(&[KeyCode::X], PlayerCommand::Drop);
+        key_bindings.bind(&[KeyCode::Q], PlayerCommand::NextItem);
+        key_bindings.bind(&[KeyCode::E], PlayerCommand::Use);
+        for (key, slot) in [
+            (KeyCode::Key1, EquipmentSlot::Weapon),
+            (KeyCode::Key2, EquipmentSlot::Armor),
+            (KeyCode::Key3, EquipmentSlot::Trinket),
+        ] {
+            key_bindings.bind(&[key], PlayerCommand::Unequip(slot));
+        }
+        key_bindings.bind(&[KeyCode::F], PlayerCommand::Fire);
+        key_bindings.bind(&[KeyCode::T], PlayerCommand::Throw);
+        key_bindings.bind(&[KeyCode::Tab], PlayerCommand::NextTarget);
+        key_bindings.bind(&[KeyCode::Return], PlayerCommand::Confirm);
+        key_bindings.bind(&[KeyCode::Escape], PlayerCommand::Cancel);
+        key_bindings.bind(&[KeyCode::C], PlayerCommand::CloseDoor);
+        key_bindings.bind(&[KeyCode::O], PlayerCommand::AutoExplore);
+        key_bindings.bind(&[KeyCode::LShift, KeyCode::Period], PlayerCommand::Descend);
+        key_bindings.bind(&[KeyCode::RShift, KeyCode::Period], PlayerCommand::Descend);
+
+        key_bindings
+    }
+}
@@ -0,0 +1,13 @@
+mod auto_explore_config;
+mod config;
+mod cursor_tile;
+mod gamepad_bindings;
+mod key_bindings;
+mod player_command;
+
+pub use auto_explore_config::*;
+pub use config::*;
+pub use cursor_tile::*;
+pub use gamepad_bindings::*;
+pub use key_bindings::*;
+pub use player_command::*;
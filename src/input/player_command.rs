@@ -0,0 +1,146 @@
+use crate::actions::Direction;
+use crate::input::{ConnectedGamepads, GamepadBindings, KeyBindings};
+use crate::items::EquipmentSlot;
+use bevy::input::gamepad::{GamepadAxis, GamepadAxisType, GamepadButton};
+use bevy::input::{Axis, Input};
+use bevy::prelude::{KeyCode, Res, ResMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Something the player wants to do, independent of the input device
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum PlayerCommand {
+    Move(Direction),
+    Wait,
+    Rest,
+    PickUp,
+    Drop,
+    /// Selects the next stack in the inventory
+    NextItem,
+    /// Uses the selected item, equipping it if it's equipment or consuming it otherwise
+    Use,
+    Unequip(EquipmentSlot),
+    /// Starts targeting a shot, or confirms the target while targeting
+    Fire,
+    /// Starts targeting a throw of the selected item, or confirms the target while targeting
+    Throw,
+    NextTarget,
+    Confirm,
+    Cancel,
+    /// Closes an open door next to the player
+    CloseDoor,
+    Descend,
+    AutoExplore,
+}
+
+/// Translates held keys and gamepad inputs into held player commands
+pub fn update_player_commands(
+    keyboard: Res<Input<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepad_bindings: Res<GamepadBindings>,
+    connected_gamepads: Res<ConnectedGamepads>,
+    mut player_commands: ResMut<Input<PlayerCommand>>,
+) {
+    player_commands.update();
+
+    let mut active_commands = HashSet::new();
+    active_commands.extend(active_combos(
+        key_bindings
+            .bindings
+            .iter()
+            .map(|binding| (binding.keys.as_slice(), binding.command)),
+        |key| keyboard.pressed(*key),
+    ));
+    for gamepad in connected_gamepads.0.iter().copied() {
+        active_commands.extend(active_combos(
+            gamepad_bindings
+                .buttons
+                .iter()
+                .map(|binding| (binding.buttons.as_slice(), binding.command)),
+            |button_type| gamepad_buttons.pressed(GamepadButton(gamepad, *button_type)),
+        ));
+
+        for (x_axis, y_axis) in [
+            (GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY),
+            (GamepadAxisType::DPadX, GamepadAxisType::DPadY),
+        ] {
+            let x = gamepad_axes
+                .get(GamepadAxis(gamepad, x_axis))
+                .unwrap_or(0.0);
+            let y = gamepad_axes
+                .get(GamepadAxis(gamepad, y_axis))
+                .unwrap_or(0.0);
+            if x.abs().max(y.abs()) < gamepad_bindings.stick_deadzone {
+                continue;
+            }
+            let direction = if x.abs() > y.abs() {
+                if x > 0.0 {
+                    Direction::Right
+                } else {
+                    Direction::Left
+                }
+            } else if y > 0.0 {
+                Direction::Up
+            } else {
+                Direction::Down
+            };
+            active_commands.insert(PlayerCommand::Move(direction));
+        }
+    }
+
+    let released_commands = player_commands
+        .get_pressed()
+        .filter(|command| !active_commands.contains(command))
+        .copied()
+        .collect::<Vec<_>>();
+    for command in released_commands {
+        player_commands.release(command);
+    }
+    for command in active_commands {
+        player_commands.press(command);
+    }
+}
+
+/// Commands that start something lasting, kept from when they're pressed until the player's turn
+/// so pressing them while other turn groups act isn't lost
+pub struct LatchedCommands(pub HashSet<PlayerCommand>);
+
+const LATCHED_COMMANDS: [PlayerCommand; 2] = [PlayerCommand::AutoExplore, PlayerCommand::Rest];
+
+/// Remembers latched commands until decide_next_action lets the player's brain see them
+pub fn latch_player_commands(
+    player_commands: Res<Input<PlayerCommand>>,
+    mut latched_commands: ResMut<LatchedCommands>,
+) {
+    for command in LATCHED_COMMANDS {
+        if player_commands.just_pressed(command) {
+            latched_commands.0.insert(command);
+        }
+    }
+}
+
+/// Returns the commands of every combo whose inputs are all held
+/// A combo whose inputs are a strict subset of another held combo is ignored,
+/// so e.g. Shift+Period doesn't also trigger Period
+fn active_combos<'a, T, I, F>(combos: I, is_held: F) -> Vec<PlayerCommand>
+where
+    T: PartialEq + 'a,
+    I: Iterator<Item = (&'a [T], PlayerCommand)>,
+    F: Fn(&T) -> bool,
+{
+    let held_combos = combos
+        .filter(|(inputs, _)| inputs.iter().all(&is_held))
+        .collect::<Vec<_>>();
+    held_combos
+        .iter()
+        .filter(|(inputs, _)| {
+            !held_combos.iter().any(|(other_inputs, _)| {
+                other_inputs.len() > inputs.len()
+                    && inputs.iter().all(|input| other_inputs.contains(input))
+            })
+        })
+        .map(|(_, command)| *command)
+        .collect()
+}
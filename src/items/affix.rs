@@ -0,0 +1,266 @@
+use crate::components::{StatusEffect, StatusEffectKind};
+use crate::items::{Item, ItemKind, StatModifiers};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// How many affixes an item rolled, shown after its name unless it's common
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Magic,
+    Rare,
+}
+
+impl Rarity {
+    /// Deeper floors make magic and rare items more likely
+    pub fn roll<R: Rng>(depth: u32, rng: &mut R) -> Self {
+        let rare_chance = (depth as f64 * 0.02).min(0.3);
+        let magic_chance = (0.1 + depth as f64 * 0.04).min(0.6);
+        let roll = rng.gen::<f64>();
+        if roll < rare_chance {
+            Rarity::Rare
+        } else if roll < rare_chance + magic_chance {
+            Rarity::Magic
+        } else {
+            Rarity::Common
+        }
+    }
+}
+
+/// Prefixes raise one of the wearer's stats, suffixes afflict whatever the wearer hits
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum AffixKind {
+    // Prefixes
+    Sharp,
+    Sturdy,
+    Swift,
+    Farsighted,
+    // Suffixes
+    OfBurning,
+    OfVenom,
+    OfFrost,
+}
+
+impl AffixKind {
+    const PREFIXES: [AffixKind; 4] = [
+        AffixKind::Sharp,
+        AffixKind::Sturdy,
+        AffixKind::Swift,
+        AffixKind::Farsighted,
+    ];
+    const SUFFIXES: [AffixKind; 3] = [AffixKind::OfBurning, AffixKind::OfVenom, AffixKind::OfFrost];
+
+    fn is_prefix(self) -> bool {
+        Self::PREFIXES.contains(&self)
+    }
+
+    /// The shallowest floor the affix can roll on
+    fn min_depth(self) -> u32 {
+        match self {
+            AffixKind::Swift => 6,
+            AffixKind::OfBurning | AffixKind::OfVenom | AffixKind::OfFrost => 3,
+            _ => 1,
+        }
+    }
+
+    fn text(self) -> &'static str {
+        match self {
+            AffixKind::Sharp => "Sharp",
+            AffixKind::Sturdy => "Sturdy",
+            AffixKind::Swift => "Swift",
+            AffixKind::Farsighted => "Farsighted",
+            AffixKind::OfBurning => "of Burning",
+            AffixKind::OfVenom => "of Venom",
+            AffixKind::OfFrost => "of Frost",
+        }
+    }
+}
+
+/// A magic property rolled onto an item, the tier scaling how strong it is
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Affix {
+    pub kind: AffixKind,
+    pub tier: u32,
+}
+
+impl Affix {
+    pub const MAX_TIER: u32 = 3;
+
+    pub fn modifiers(&self) -> StatModifiers {
+        let tier = self.tier as i32;
+        match self.kind {
+            AffixKind::Sharp => StatModifiers {
+                attack: tier,
+                ..Default::default()
+            },
+            AffixKind::Sturdy => StatModifiers {
+                defense: tier,
+                ..Default::default()
+            },
+            AffixKind::Swift => StatModifiers {
+                speed: 1,
+                ..Default::default()
+            },
+            AffixKind::Farsighted => StatModifiers {
+                sight_radius: tier,
+                ..Default::default()
+            },
+            AffixKind::OfBurning | AffixKind::OfVenom | AffixKind::OfFrost => {
+                StatModifiers::default()
+            }
+        }
+    }
+
+    /// What the affix applies to whatever the wearer hits in melee
+    pub fn on_hit_effect(&self) -> Option<StatusEffect> {
+        match self.kind {
+            AffixKind::OfBurning => Some(StatusEffect::new(
+                StatusEffectKind::Burning,
+                1 + self.tier,
+                1,
+            )),
+            AffixKind::OfVenom => Some(StatusEffect::new(StatusEffectKind::Poison, 3, self.tier)),
+            // Slow loses every other turn, so each tier costs the target one turn
+            AffixKind::OfFrost => Some(StatusEffect::new(StatusEffectKind::Slow, 2 * self.tier, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// Rolls a rarity for the item and gives it that many affixes, at most one prefix and one suffix
+/// Deeper floors allow more affixes and higher tiers, and only equipment gets any
+pub fn roll_affixes<R: Rng>(item: &mut Item, depth: u32, rng: &mut R) {
+    if !matches!(item.kind, ItemKind::Equipment { .. }) {
+        return;
+    }
+
+    item.rarity = Rarity::roll(depth, rng);
+    let affix_count = match item.rarity {
+        Rarity::Common => 0,
+        Rarity::Magic => 1,
+        Rarity::Rare => 2,
+    };
+    let max_tier = (1 + depth / 4).min(Affix::MAX_TIER);
+    let mut pools = [&AffixKind::PREFIXES[..], &AffixKind::SUFFIXES[..]];
+    pools.shuffle(rng);
+    for pool in pools.iter().take(affix_count) {
+        let available = pool
+            .iter()
+            .copied()
+            .filter(|kind| kind.min_depth() <= depth)
+            .collect::<Vec<_>>();
+        if let Some(kind) = available.choose(rng) {
+            item.affixes.push(Affix {
+                kind: *kind,
+                tier: rng.gen_range(1..=max_tier),
+            });
+        }
+    }
+}
+
+impl Item {
+    /// The base name with the prefixes before it and the suffixes after it
+    pub fn display_name(&self) -> String {
+        let mut words = self
+            .affixes
+            .iter()
+            .filter(|affix| affix.kind.is_prefix())
+            .map(|affix| affix.kind.text())
+            .collect::<Vec<_>>();
+        words.push(&self.name);
+        words.extend(
+            self.affixes
+                .iter()
+                .filter(|affix| !affix.kind.is_prefix())
+                .map(|affix| affix.kind.text()),
+        );
+        words.join(" ")
+    }
+
+    /// The base modifiers of equipment plus those of its affixes
+    pub fn modifiers(&self) -> StatModifiers {
+        let base = match self.kind {
+            ItemKind::Equipment { modifiers, .. } => modifiers,
+            _ => StatModifiers::default(),
+        };
+        self.affixes
+            .iter()
+            .fold(base, |total, affix| total + affix.modifiers())
+    }
+
+    pub fn on_hit_effects(&self) -> impl Iterator<Item = StatusEffect> + '_ {
+        self.affixes
+            .iter()
+            .filter_map(|affix| affix.on_hit_effect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::{gold_coins, short_sword};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn deeper_floors_roll_rarer_items() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut count = |depth, rarity| {
+            (0..1000)
+                .filter(|_| Rarity::roll(depth, &mut rng) == rarity)
+                .count()
+        };
+        assert!(count(1, Rarity::Common) > count(15, Rarity::Common));
+        assert!(count(1, Rarity::Magic) < count(15, Rarity::Magic));
+        assert!(count(1, Rarity::Rare) < count(15, Rarity::Rare));
+    }
+
+    #[test]
+    fn rare_items_get_one_prefix_and_one_suffix() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut rare_items = 0;
+        for _ in 0..1000 {
+            let mut item = short_sword();
+            roll_affixes(&mut item, 20, &mut rng);
+            if item.rarity != Rarity::Rare {
+                continue;
+            }
+            rare_items += 1;
+            let prefixes = item.affixes.iter().filter(|affix| affix.kind.is_prefix());
+            assert_eq!(prefixes.count(), 1);
+            assert_ne!(item.affixes[0].kind, item.affixes[1].kind);
+        }
+        assert!(rare_items > 0);
+    }
+
+    #[test]
+    fn affixes_match_rarity_with_at_most_one_prefix_and_suffix() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for depth in 1..=20 {
+            let mut item = short_sword();
+            roll_affixes(&mut item, depth, &mut rng);
+            let expected = match item.rarity {
+                Rarity::Common => 0,
+                Rarity::Magic => 1,
+                Rarity::Rare => 2,
+            };
+            assert_eq!(item.affixes.len(), expected);
+            let prefixes = item.affixes.iter().filter(|affix| affix.kind.is_prefix());
+            assert!(prefixes.count() <= 1);
+            assert!(item
+                .affixes
+                .iter()
+                .all(|affix| affix.kind.min_depth() <= depth && affix.tier <= Affix::MAX_TIER));
+        }
+    }
+
+    #[test]
+    fn only_equipment_gets_affixes() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut item = gold_coins();
+        roll_affixes(&mut item, 20, &mut rng);
+        assert_eq!(item, gold_coins());
+    }
+}
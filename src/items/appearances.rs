@@ -0,0 +1,81 @@
+use crate::items::{ConsumableEffect, Item, ItemKind, ItemStack, Rarity};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::{HashMap, HashSet};
+
+const POTION_APPEARANCES: [&str; 6] = [
+    "Murky Potion",
+    "Fizzy Potion",
+    "Golden Potion",
+    "Smoking Potion",
+    "Viscous Potion",
+    "Glowing Potion",
+];
+const SCROLL_APPEARANCES: [&str; 6] = [
+    "Scroll labeled ZELGO MER",
+    "Scroll labeled XIXAXA",
+    "Scroll labeled VELOX NEB",
+    "Scroll labeled ELBIB YLOH",
+    "Scroll labeled KIRJE",
+    "Scroll labeled PRATYAVAYAH",
+];
+
+/// What each kind of consumable looks like this run, and which kinds have been identified
+/// Unidentified consumables are only known by their appearance
+pub struct ItemAppearances {
+    appearances: HashMap<ConsumableEffect, String>,
+    identified: HashSet<ConsumableEffect>,
+}
+
+impl ItemAppearances {
+    /// The same seed always gives the same appearances
+    pub fn new(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut appearances = HashMap::new();
+        for (effects, pool) in [
+            (&ConsumableEffect::POTIONS[..], POTION_APPEARANCES),
+            (&ConsumableEffect::SCROLLS[..], SCROLL_APPEARANCES),
+        ] {
+            let mut pool = pool.to_vec();
+            pool.shuffle(&mut rng);
+            for (effect, appearance) in effects.iter().zip(pool) {
+                appearances.insert(*effect, appearance.to_owned());
+            }
+        }
+        Self {
+            appearances,
+            identified: HashSet::new(),
+        }
+    }
+
+    pub fn is_identified(&self, effect: ConsumableEffect) -> bool {
+        self.identified.contains(&effect)
+    }
+
+    pub fn identify(&mut self, effect: ConsumableEffect) {
+        self.identified.insert(effect);
+    }
+
+    /// The item's true name, or its appearance if it hasn't been identified yet
+    pub fn name(&self, item: &Item) -> String {
+        match item.kind {
+            ItemKind::Consumable(effect) if !self.is_identified(effect) => {
+                self.appearances[&effect].clone()
+            }
+            _ => item.display_name(),
+        }
+    }
+
+    /// The name along with the rarity of anything better than common, and how many there are
+    pub fn describe(&self, stack: &ItemStack) -> String {
+        let mut description = self.name(&stack.item);
+        if stack.item.rarity != Rarity::Common {
+            description = format!("{} ({:?})", description, stack.item.rarity);
+        }
+        if stack.count > 1 {
+            description = format!("{} x{}", description, stack.count);
+        }
+        description
+    }
+}
@@ -0,0 +1,165 @@
+use crate::items::{
+    roll_affixes, ConsumableEffect, EquipmentSlot, Item, ItemKind, Rarity, StatModifiers,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const THROWING_KNIFE_DAMAGE: u32 = 3;
+
+pub fn throwing_knife() -> Item {
+    Item {
+        name: "Throwing Knife".to_owned(),
+        sprite: "throwing_knife".to_owned(),
+        max_stack: 12,
+        kind: ItemKind::Thrown {
+            damage: THROWING_KNIFE_DAMAGE,
+        },
+        rarity: Rarity::Common,
+        affixes: Vec::new(),
+    }
+}
+
+pub fn gold_coins() -> Item {
+    Item {
+        name: "Gold Coins".to_owned(),
+        sprite: "gold_coins".to_owned(),
+        max_stack: 999,
+        kind: ItemKind::Misc,
+        rarity: Rarity::Common,
+        affixes: Vec::new(),
+    }
+}
+
+/// Keys only work on the floor they were found on, so they're named after it
+pub fn key(floor: u32) -> Item {
+    Item {
+        name: format!("Key to Floor {}", floor),
+        sprite: "key".to_owned(),
+        max_stack: 5,
+        kind: ItemKind::Key { floor },
+        rarity: Rarity::Common,
+        affixes: Vec::new(),
+    }
+}
+
+fn equipment(name: &str, sprite: &str, slot: EquipmentSlot, modifiers: StatModifiers) -> Item {
+    Item {
+        name: name.to_owned(),
+        sprite: sprite.to_owned(),
+        max_stack: 1,
+        kind: ItemKind::Equipment { slot, modifiers },
+        rarity: Rarity::Common,
+        affixes: Vec::new(),
+    }
+}
+
+pub fn short_sword() -> Item {
+    equipment(
+        "Short Sword",
+        "weapon",
+        EquipmentSlot::Weapon,
+        StatModifiers {
+            attack: 2,
+            ..Default::default()
+        },
+    )
+}
+
+pub fn war_axe() -> Item {
+    equipment(
+        "War Axe",
+        "weapon",
+        EquipmentSlot::Weapon,
+        StatModifiers {
+            attack: 4,
+            defense: -1,
+            ..Default::default()
+        },
+    )
+}
+
+pub fn leather_armor() -> Item {
+    equipment(
+        "Leather Armor",
+        "armor",
+        EquipmentSlot::Armor,
+        StatModifiers {
+            defense: 1,
+            ..Default::default()
+        },
+    )
+}
+
+pub fn chain_mail() -> Item {
+    equipment(
+        "Chain Mail",
+        "armor",
+        EquipmentSlot::Armor,
+        StatModifiers {
+            defense: 3,
+            sight_radius: -1,
+            ..Default::default()
+        },
+    )
+}
+
+pub fn ring_of_haste() -> Item {
+    equipment(
+        "Ring of Haste",
+        "trinket",
+        EquipmentSlot::Trinket,
+        StatModifiers {
+            speed: 1,
+            ..Default::default()
+        },
+    )
+}
+
+pub fn owl_amulet() -> Item {
+    equipment(
+        "Owl Amulet",
+        "trinket",
+        EquipmentSlot::Trinket,
+        StatModifiers {
+            sight_radius: 3,
+            ..Default::default()
+        },
+    )
+}
+
+/// Potions and scrolls are always sprited the same, so only their appearance tells them apart
+pub fn consumable(effect: ConsumableEffect) -> Item {
+    let (name, sprite) = match effect {
+        ConsumableEffect::Healing => ("Potion of Healing", "potion"),
+        ConsumableEffect::Haste => ("Potion of Haste", "potion"),
+        ConsumableEffect::Regeneration => ("Potion of Regeneration", "potion"),
+        ConsumableEffect::Teleportation => ("Scroll of Teleportation", "scroll"),
+        ConsumableEffect::MagicMapping => ("Scroll of Magic Mapping", "scroll"),
+        ConsumableEffect::Fireball => ("Scroll of Fireball", "scroll"),
+        ConsumableEffect::Identify => ("Scroll of Identify", "scroll"),
+    };
+    Item {
+        name: name.to_owned(),
+        sprite: sprite.to_owned(),
+        max_stack: 5,
+        kind: ItemKind::Consumable(effect),
+        rarity: Rarity::Common,
+        affixes: Vec::new(),
+    }
+}
+
+/// A random piece of equipment, more likely to have rolled affixes the deeper it's found
+pub fn random_equipment<R: Rng>(depth: u32, rng: &mut R) -> Item {
+    let mut equipment = [
+        short_sword,
+        war_axe,
+        leather_armor,
+        chain_mail,
+        ring_of_haste,
+        owl_amulet,
+    ]
+    .choose(rng)
+    .unwrap()();
+    roll_affixes(&mut equipment, depth, rng);
+    equipment
+}
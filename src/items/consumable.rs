@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum ConsumableEffect {
+    Healing,
+    Haste,
+    Regeneration,
+    Teleportation,
+    MagicMapping,
+    Fireball,
+    Identify,
+}
+
+impl ConsumableEffect {
+    pub const POTIONS: [ConsumableEffect; 3] = [
+        ConsumableEffect::Healing,
+        ConsumableEffect::Haste,
+        ConsumableEffect::Regeneration,
+    ];
+    pub const SCROLLS: [ConsumableEffect; 4] = [
+        ConsumableEffect::Teleportation,
+        ConsumableEffect::MagicMapping,
+        ConsumableEffect::Fireball,
+        ConsumableEffect::Identify,
+    ];
+
+    /// Potions shatter when thrown, applying their effect around where they land
+    pub fn is_potion(self) -> bool {
+        Self::POTIONS.contains(&self)
+    }
+
+    /// Whether the user has to choose a tile for the effect, see Aim
+    pub fn needs_target(self) -> bool {
+        matches!(self, ConsumableEffect::Fireball)
+    }
+}
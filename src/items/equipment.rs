@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum EquipmentSlot {
+    Weapon,
+    Armor,
+    Trinket,
+}
+
+impl EquipmentSlot {
+    pub const ALL: [EquipmentSlot; 3] = [
+        EquipmentSlot::Weapon,
+        EquipmentSlot::Armor,
+        EquipmentSlot::Trinket,
+    ];
+}
+
+/// Added to the wearer's stats while the item is equipped
+/// Speed is extra actions per turn, sight radius is extra tiles of view
+#[derive(Clone, Copy, Default, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatModifiers {
+    pub attack: i32,
+    pub defense: i32,
+    pub speed: i32,
+    pub sight_radius: i32,
+}
+
+impl Add for StatModifiers {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            attack: self.attack + other.attack,
+            defense: self.defense + other.defense,
+            speed: self.speed + other.speed,
+            sight_radius: self.sight_radius + other.sight_radius,
+        }
+    }
+}
+
+/// Applies a modifier to a stat that can't go below the minimum
+pub fn modify_stat(stat: u32, modifier: i32, minimum: u32) -> u32 {
+    (stat as i32 + modifier).max(minimum as i32) as u32
+}
@@ -0,0 +1,69 @@
+use crate::items::{Affix, ConsumableEffect, EquipmentSlot, Rarity, StatModifiers};
+use serde::{Deserialize, Serialize};
+
+/// Something that can lie on the floor and be carried
+/// Items that are equal stack together
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Item {
+    pub name: String,
+    /// Key into the material map
+    pub sprite: String,
+    /// How many fit in one inventory slot, 1 for items that don't stack
+    pub max_stack: u32,
+    #[serde(default)]
+    pub kind: ItemKind,
+    #[serde(default)]
+    pub rarity: Rarity,
+    #[serde(default)]
+    pub affixes: Vec<Affix>,
+}
+
+#[derive(Clone, Default, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ItemKind {
+    /// Has no use of its own
+    #[default]
+    Misc,
+    Equipment {
+        slot: EquipmentSlot,
+        modifiers: StatModifiers,
+    },
+    /// Used up to apply its effect, and unidentified until then, see ItemAppearances
+    Consumable(ConsumableEffect),
+    /// Unlocks the locked doors on the floor it was found on, see OpenDoorAction
+    Key { floor: u32 },
+    /// Made to be thrown, hurting whatever it hits, see ThrowAction
+    Thrown { damage: u32 },
+}
+
+impl ItemKind {
+    pub fn category(&self) -> ItemCategory {
+        match self {
+            Self::Misc | Self::Thrown { .. } => ItemCategory::Misc,
+            Self::Equipment { .. } => ItemCategory::Equipment,
+            Self::Consumable(_) => ItemCategory::Consumable,
+            Self::Key { .. } => ItemCategory::Key,
+        }
+    }
+}
+
+/// An item kind without its details, for filtering items in configs
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ItemCategory {
+    Misc,
+    Equipment,
+    Consumable,
+    Key,
+}
+
+/// Several of the same item, carried or lying together
+#[derive(Clone, Debug)]
+pub struct ItemStack {
+    pub item: Item,
+    pub count: u32,
+}
+
+impl ItemStack {
+    pub fn new(item: Item, count: u32) -> Self {
+        Self { item, count }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::items::LootTables;
+use anyhow::anyhow;
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+
+#[derive(Default)]
+pub struct LootTablesLoader;
+
+impl AssetLoader for LootTablesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let loot_tables = ron::de::from_bytes::<LootTables>(bytes)?;
+            for table in loot_tables.tables.iter() {
+                if !(0.0..=1.0).contains(&table.chance) {
+                    return Err(anyhow!(
+                        "{:?} has a chance of {}, which isn't between 0 and 1",
+                        table.source,
+                        table.chance
+                    ));
+                }
+            }
+            load_context.set_default_asset(LoadedAsset::new(loot_tables));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["loot_tables.ron"]
+    }
+}
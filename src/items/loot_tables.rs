@@ -0,0 +1,317 @@
+use crate::actions::RoomKind;
+use crate::bundles::ItemPile;
+use crate::dungeon::{DungeonDepth, RunRng};
+use crate::items::{
+    chain_mail, consumable, gold_coins, leather_armor, owl_amulet, random_equipment, ring_of_haste,
+    roll_affixes, short_sword, throwing_knife, war_axe, ConsumableEffect, Item, ItemStack,
+};
+use bevy::asset::{AssetServer, Assets, Handle, LoadState};
+use bevy::math::IVec2;
+use bevy::prelude::{Mut, World};
+use bevy::reflect::TypeUuid;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Deserialize;
+
+/// What items are found where, loaded from assets/items.loot_tables.ron
+#[derive(Clone, Deserialize, TypeUuid)]
+#[uuid = "b0a6f3d2-4c1e-4f7a-9d85-6e2b1c9a7f34"]
+pub struct LootTables {
+    pub tables: Vec<LootTable>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LootTable {
+    pub source: LootSource,
+    /// The shallowest and deepest floors the table is used on
+    pub depths: (u32, u32),
+    /// Chance of anything being found at all
+    pub chance: f64,
+    /// How many entries are rolled when something is found
+    pub rolls: (u32, u32),
+    pub entries: Vec<LootEntry>,
+}
+
+/// Where loot comes from
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub enum LootSource {
+    /// Lying on the floor of a room of that kind
+    Room(RoomKind),
+    /// Dropped when a monster of that archetype dies, see MonsterDefinition::loot
+    Monster(String),
+}
+
+#[derive(Clone, Deserialize)]
+pub struct LootEntry {
+    pub item: LootItem,
+    pub weight: u32,
+    /// How many of the item are in the stack, capped by how many can stack
+    #[serde(default = "default_count")]
+    pub count: (u32, u32),
+}
+
+fn default_count() -> (u32, u32) {
+    (1, 1)
+}
+
+/// An item from the catalog, or a random one from a group of them
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+pub enum LootItem {
+    GoldCoins,
+    ThrowingKnife,
+    ShortSword,
+    WarAxe,
+    LeatherArmor,
+    ChainMail,
+    RingOfHaste,
+    OwlAmulet,
+    Consumable(ConsumableEffect),
+    AnyPotion,
+    AnyScroll,
+    AnyEquipment,
+}
+
+impl LootItem {
+    /// Equipment rolls its affixes for the depth it's found on
+    pub fn create<R: Rng>(self, depth: u32, rng: &mut R) -> Item {
+        let mut item = match self {
+            LootItem::GoldCoins => gold_coins(),
+            LootItem::ThrowingKnife => throwing_knife(),
+            LootItem::ShortSword => short_sword(),
+            LootItem::WarAxe => war_axe(),
+            LootItem::LeatherArmor => leather_armor(),
+            LootItem::ChainMail => chain_mail(),
+            LootItem::RingOfHaste => ring_of_haste(),
+            LootItem::OwlAmulet => owl_amulet(),
+            LootItem::Consumable(effect) => consumable(effect),
+            LootItem::AnyPotion => consumable(*ConsumableEffect::POTIONS.choose(rng).unwrap()),
+            LootItem::AnyScroll => consumable(*ConsumableEffect::SCROLLS.choose(rng).unwrap()),
+            LootItem::AnyEquipment => return random_equipment(depth, rng),
+        };
+        roll_affixes(&mut item, depth, rng);
+        item
+    }
+}
+
+impl LootTables {
+    /// Rolls the stacks found at the source on the floor
+    /// Floors deeper than every table for the source use its deepest one
+    pub fn roll<R: Rng>(&self, source: &LootSource, depth: u32, rng: &mut R) -> Vec<ItemStack> {
+        let for_source = self
+            .tables
+            .iter()
+            .filter(|table| table.source == *source)
+            .collect::<Vec<_>>();
+        let mut tables = for_source
+            .iter()
+            .copied()
+            .filter(|table| (table.depths.0..=table.depths.1).contains(&depth))
+            .collect::<Vec<_>>();
+        if tables.is_empty() {
+            tables.extend(for_source.iter().max_by_key(|table| table.depths.1));
+        }
+
+        let mut stacks = Vec::new();
+        for table in tables {
+            if !rng.gen_bool(table.chance) {
+                continue;
+            }
+            let (fewest, most) = table.rolls;
+            for _ in 0..rng.gen_range(fewest..=most.max(fewest)) {
+                let entry = match table.entries.choose_weighted(rng, |entry| entry.weight) {
+                    Ok(entry) => entry,
+                    Err(_) => break,
+                };
+                let item = entry.item.create(depth, rng);
+                let (smallest, largest) = entry.count;
+                let count = rng
+                    .gen_range(smallest..=largest.max(smallest))
+                    .clamp(1, item.max_stack);
+                stacks.push(ItemStack::new(item, count));
+            }
+        }
+        stacks
+    }
+}
+
+/// The loot tables asset, reloaded while the game runs like the monster definitions
+pub struct LootLibrary {
+    loot_tables: Handle<LootTables>,
+}
+
+impl LootLibrary {
+    pub fn load(asset_server: &AssetServer) -> Self {
+        Self {
+            loot_tables: asset_server.load("items.loot_tables.ron"),
+        }
+    }
+
+    /// Whether the tables have either loaded or failed to
+    pub fn is_ready(&self, asset_server: &AssetServer) -> bool {
+        !matches!(
+            asset_server.get_load_state(self.loot_tables.id),
+            LoadState::NotLoaded | LoadState::Loading
+        )
+    }
+
+    pub fn loot_tables<'a>(&self, loot_tables: &'a Assets<LootTables>) -> Option<&'a LootTables> {
+        loot_tables.get(&self.loot_tables)
+    }
+}
+
+/// Rolls loot from the source with the run's RNG and leaves it lying at the position
+pub fn spawn_loot(world: &mut World, source: &LootSource, position: IVec2) {
+    world.resource_scope(|world, mut run_rng: Mut<RunRng>| {
+        spawn_loot_with_rng(world, source, position, &mut run_rng.0)
+    });
+}
+
+/// Rolls loot from the source with the given RNG and leaves it lying at the position
+/// Nothing is spawned if the loot tables haven't loaded
+pub fn spawn_loot_with_rng<R: Rng>(
+    world: &mut World,
+    source: &LootSource,
+    position: IVec2,
+    rng: &mut R,
+) {
+    let depth = world.get_resource::<DungeonDepth>().unwrap().0;
+    let loot_tables = world.get_resource::<Assets<LootTables>>().unwrap();
+    let stacks = match world
+        .get_resource::<LootLibrary>()
+        .unwrap()
+        .loot_tables(loot_tables)
+    {
+        Some(loot_tables) => loot_tables.roll(source, depth, rng),
+        None => Vec::new(),
+    };
+
+    for stack in stacks {
+        world
+            .spawn()
+            .insert_bundle(ItemPile::new(stack, position.x, position.y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn table(depths: (u32, u32), chance: f64, item: LootItem) -> LootTable {
+        LootTable {
+            source: LootSource::Room(RoomKind::Storeroom),
+            depths,
+            chance,
+            rolls: (2, 2),
+            entries: vec![LootEntry {
+                item,
+                weight: 1,
+                count: (3, 5),
+            }],
+        }
+    }
+
+    fn storeroom() -> LootSource {
+        LootSource::Room(RoomKind::Storeroom)
+    }
+
+    #[test]
+    fn rolls_the_table_for_the_depth() {
+        let loot_tables = LootTables {
+            tables: vec![
+                table((1, 3), 1.0, LootItem::GoldCoins),
+                table((4, 8), 1.0, LootItem::ThrowingKnife),
+            ],
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let stacks = loot_tables.roll(&storeroom(), 2, &mut rng);
+        assert_eq!(stacks.len(), 2);
+        for stack in stacks {
+            assert_eq!(stack.item, gold_coins());
+            assert!((3..=5).contains(&stack.count));
+        }
+    }
+
+    #[test]
+    fn floors_past_every_table_use_the_deepest() {
+        let loot_tables = LootTables {
+            tables: vec![
+                table((1, 3), 1.0, LootItem::GoldCoins),
+                table((4, 8), 1.0, LootItem::ThrowingKnife),
+            ],
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let stacks = loot_tables.roll(&storeroom(), 30, &mut rng);
+        assert!(!stacks.is_empty());
+        assert!(stacks.iter().all(|stack| stack.item == throwing_knife()));
+    }
+
+    #[test]
+    fn other_sources_and_failed_chances_find_nothing() {
+        let loot_tables = LootTables {
+            tables: vec![table((1, 8), 0.0, LootItem::GoldCoins)],
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(loot_tables.roll(&storeroom(), 1, &mut rng).is_empty());
+        let monster = LootSource::Monster("Skeleton".to_owned());
+        assert!(loot_tables.roll(&monster, 1, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn counts_are_capped_by_the_stack_size() {
+        let mut loot_table = table((1, 8), 1.0, LootItem::ShortSword);
+        loot_table.entries[0].count = (5, 5);
+        let loot_tables = LootTables {
+            tables: vec![loot_table],
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let stacks = loot_tables.roll(&storeroom(), 1, &mut rng);
+        assert!(stacks.iter().all(|stack| stack.count == 1));
+    }
+
+    #[test]
+    fn entries_are_picked_by_weight() {
+        let mut loot_table = table((1, 8), 1.0, LootItem::GoldCoins);
+        loot_table.rolls = (1, 1);
+        loot_table.entries[0].weight = 3;
+        for (item, weight) in [(LootItem::ThrowingKnife, 1), (LootItem::ShortSword, 0)] {
+            loot_table.entries.push(LootEntry {
+                item,
+                weight,
+                count: (1, 1),
+            });
+        }
+        let loot_tables = LootTables {
+            tables: vec![loot_table],
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+        let stacks = (0..1000)
+            .flat_map(|_| loot_tables.roll(&storeroom(), 1, &mut rng))
+            .collect::<Vec<_>>();
+        let count = |item: &Item| {
+            stacks
+                .iter()
+                .filter(|stack| stack.item.name == item.name)
+                .count()
+        };
+        assert!(count(&gold_coins()) > 2 * count(&throwing_knife()));
+        assert!(count(&throwing_knife()) > 0);
+        assert_eq!(count(&short_sword()), 0);
+    }
+
+    #[test]
+    fn empty_and_weightless_tables_find_nothing() {
+        let mut empty = table((1, 8), 1.0, LootItem::GoldCoins);
+        empty.entries.clear();
+        let mut weightless = table((1, 8), 1.0, LootItem::GoldCoins);
+        weightless.entries[0].weight = 0;
+        let mut rng = StdRng::seed_from_u64(0);
+        for loot_table in [empty, weightless] {
+            let loot_tables = LootTables {
+                tables: vec![loot_table],
+            };
+            assert!(loot_tables.roll(&storeroom(), 1, &mut rng).is_empty());
+        }
+    }
+}
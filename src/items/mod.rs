@@ -0,0 +1,17 @@
+mod affix;
+mod appearances;
+mod catalog;
+mod consumable;
+mod equipment;
+mod item;
+mod loader;
+mod loot_tables;
+
+pub use affix::*;
+pub use appearances::*;
+pub use catalog::*;
+pub use consumable::*;
+pub use equipment::*;
+pub use item::*;
+pub use loader::*;
+pub use loot_tables::*;
@@ -2,36 +2,103 @@ use crate::actions::RegenerateDungeonAction;
 use crate::components::KeepBetweenFloors;
 use crate::world::WorldExt;
 use actions::{perform_next_action, ActionStack};
+use bevy::input::Input;
+use bevy::log::info;
 use bevy::prelude::{
-    App, AssetServer, Assets, BuildWorldChildren, ClearColor, Color,
+    AddAsset, App, AssetServer, Assets, BuildWorldChildren, ClearColor, Color,
     ExclusiveSystemDescriptorCoercion, IntoExclusiveSystem, IntoSystem, OrthographicCameraBundle,
-    Transform, World,
+    ParallelSystemDescriptorCoercion, Transform, World,
 };
 use bevy::sprite::ColorMaterial;
 use bevy::window::WindowDescriptor;
 use bevy::DefaultPlugins;
-use bundles::{Player, SkeletonScout, MATERIAL_MAP};
-use components::{decide_next_action, determine_turn_group, TurnGroup};
-use std::collections::HashMap;
+use bundles::{Player, TileHighlight, MATERIAL_MAP};
+use components::{
+    decide_next_action, determine_turn_group, regenerate_health, reveal_disguises_near_player,
+    tick_status_effects, tick_tile_effects, update_viewsheds, TurnGroup,
+};
+use dungeon::{run_seed, DungeonDepth, DungeonLayout, RunRng, RunSeed};
+use input::{
+    latch_player_commands, track_connected_gamepads, update_cursor_tile, update_player_commands,
+    AutoExploreConfig, ConnectedGamepads, CursorTile, GamepadBindings, KeyBindings,
+    LatchedCommands, PlayerCommand,
+};
+use items::{ItemAppearances, LootLibrary, LootTables, LootTablesLoader};
+use monsters::{
+    report_reloaded_monster_definitions, MonsterDefinition, MonsterDefinitionLoader,
+    MonsterLibrary, SpawnTables, SpawnTablesLoader,
+};
+use std::collections::{HashMap, HashSet};
+use ui::{
+    highlight_hovered_tile, highlight_targeting, inspect_hovered_tile, select_next_item,
+    update_targeting, Targeting, WINDOW_TITLE,
+};
 
 mod actions;
+mod ai;
 mod bundles;
 mod components;
+mod dungeon;
+mod input;
+mod items;
+mod monsters;
+mod ui;
 mod world;
 
 fn main() {
+    let seed = run_seed();
     App::build()
         .insert_resource(WindowDescriptor {
             width: 480.0,
             height: 480.0,
+            title: WINDOW_TITLE.to_owned(),
             ..Default::default()
         })
         .insert_resource(ClearColor(Color::rgb(0.05, 0.05, 0.05)))
         .insert_resource(ActionStack::new())
         .insert_resource(TurnGroup::Neutral)
+        .insert_resource(KeyBindings::load())
+        .insert_resource(GamepadBindings::load())
+        .insert_resource(ConnectedGamepads(HashSet::new()))
+        .insert_resource(AutoExploreConfig::load())
+        .insert_resource(Input::<PlayerCommand>::default())
+        .insert_resource(LatchedCommands(HashSet::new()))
+        .insert_resource(CursorTile::new())
+        .insert_resource(Targeting::new())
+        .insert_resource(DungeonDepth(0))
+        .insert_resource(ItemAppearances::new(seed))
+        .insert_resource(RunSeed(seed))
+        .insert_resource(RunRng::new(seed))
+        .insert_resource(DungeonLayout::new(
+            HashSet::new(),
+            HashSet::new(),
+            Vec::new(),
+        ))
         .add_plugins(DefaultPlugins)
+        .add_asset::<MonsterDefinition>()
+        .init_asset_loader::<MonsterDefinitionLoader>()
+        .add_asset::<SpawnTables>()
+        .init_asset_loader::<SpawnTablesLoader>()
+        .add_asset::<LootTables>()
+        .init_asset_loader::<LootTablesLoader>()
+        .add_startup_system((move || info!("Run seed: {}", seed)).system())
         .add_startup_system(init_game.exclusive_system())
-        .add_system(determine_turn_group.system())
+        .add_system(determine_turn_group.system().label("turn_group"))
+        .add_system(regenerate_health.system())
+        .add_system(tick_status_effects.system().after("turn_group"))
+        .add_system(reveal_disguises_near_player.system())
+        .add_system(tick_tile_effects.system())
+        .add_system(track_connected_gamepads.system())
+        .add_system(update_player_commands.system().label("input"))
+        .add_system(update_cursor_tile.system().label("input"))
+        .add_system(latch_player_commands.system().after("input"))
+        .add_system(update_targeting.system().after("input").label("targeting"))
+        .add_system(highlight_targeting.system().after("targeting"))
+        .add_system(update_viewsheds.system())
+        .add_system(highlight_hovered_tile.system())
+        .add_system(inspect_hovered_tile.system())
+        .add_system(select_next_item.system().after("input"))
+        .add_system(report_reloaded_monster_definitions.system())
         .add_system(decide_next_action.exclusive_system().at_end().label("x"))
         .add_system(perform_next_action.exclusive_system().at_end().after("x"))
         .run();
@@ -48,31 +115,80 @@ fn init_game(world: &mut World) {
     for material in [
         "floor_alt.png",
         "floor.png",
-        "skeleton_scout.png",
         "soul_spectre.png",
         "wall_mossy.png",
         "wall.png",
     ] {
         material_map.insert(material, materials.add(assets.load(material).into()));
     }
+    material_map.insert("armor", materials.add(Color::rgb(0.55, 0.4, 0.3).into()));
+    material_map.insert(
+        "door_closed",
+        materials.add(Color::rgb(0.45, 0.3, 0.15).into()),
+    );
+    material_map.insert(
+        "door_locked",
+        materials.add(Color::rgb(0.35, 0.2, 0.1).into()),
+    );
+    material_map.insert(
+        "door_open",
+        materials.add(Color::rgba(0.45, 0.3, 0.15, 0.3).into()),
+    );
+    material_map.insert(
+        "explosion",
+        materials.add(Color::rgba(1.0, 0.85, 0.3, 0.8).into()),
+    );
+    material_map.insert(
+        "fire",
+        materials.add(Color::rgba(1.0, 0.45, 0.1, 0.6).into()),
+    );
+    material_map.insert(
+        "gold_coins",
+        materials.add(Color::rgb(1.0, 0.8, 0.2).into()),
+    );
+    material_map.insert("key", materials.add(Color::rgb(0.9, 0.75, 0.3).into()));
+    material_map.insert("potion", materials.add(Color::rgb(0.85, 0.25, 0.45).into()));
+    material_map.insert(
+        "projectile",
+        materials.add(Color::rgb(0.85, 0.8, 0.7).into()),
+    );
+    material_map.insert("scroll", materials.add(Color::rgb(0.95, 0.9, 0.75).into()));
+    material_map.insert(
+        "stairs",
+        materials.add(Color::rgba(0.35, 0.6, 1.0, 0.6).into()),
+    );
+    material_map.insert(
+        "throwing_knife",
+        materials.add(Color::rgb(0.7, 0.75, 0.8).into()),
+    );
+    material_map.insert(
+        "toxic_puddle",
+        materials.add(Color::rgba(0.5, 0.85, 0.2, 0.45).into()),
+    );
+    material_map.insert(
+        "treasure_chest",
+        materials.add(Color::rgb(0.75, 0.55, 0.2).into()),
+    );
+    material_map.insert(
+        "web",
+        materials.add(Color::rgba(0.9, 0.9, 0.9, 0.35).into()),
+    );
+    material_map.insert(
+        "targeting_area",
+        materials.add(Color::rgba(1.0, 0.5, 0.2, 0.35).into()),
+    );
+    material_map.insert(
+        "targeting_path",
+        materials.add(Color::rgba(1.0, 1.0, 0.6, 0.15).into()),
+    );
+    material_map.insert("trinket", materials.add(Color::rgb(0.6, 0.3, 0.9).into()));
+    material_map.insert("weapon", materials.add(Color::rgb(0.8, 0.8, 0.85).into()));
+    material_map.insert(
+        "tile_highlight",
+        materials.add(Color::rgba(1.0, 1.0, 0.6, 0.25).into()),
+    );
     MATERIAL_MAP.map.set(material_map).unwrap();
 
-    world
-        .spawn()
-        .insert_bundle(SkeletonScout::new(0, 0))
-        .insert(KeepBetweenFloors);
-    world
-        .spawn()
-        .insert_bundle(SkeletonScout::new(1, 0))
-        .insert(KeepBetweenFloors);
-    world
-        .spawn()
-        .insert_bundle(SkeletonScout::new(0, 1))
-        .insert(KeepBetweenFloors);
-    world
-        .spawn()
-        .insert_bundle(SkeletonScout::new(1, 1))
-        .insert(KeepBetweenFloors);
     world
         .spawn()
         .insert_bundle(Player::new(2, 2))
@@ -82,5 +198,12 @@ fn init_game(world: &mut World) {
             camera.get_mut::<Transform>().unwrap().translation.z -= 1.0;
         });
 
+    world.spawn().insert_bundle(TileHighlight::new());
+
+    let asset_server = world.get_resource::<AssetServer>().unwrap();
+    let monster_library = MonsterLibrary::load(asset_server);
+    let loot_library = LootLibrary::load(asset_server);
+    world.insert_resource(monster_library);
+    world.insert_resource(loot_library);
     world.add_action(RegenerateDungeonAction::new());
 }
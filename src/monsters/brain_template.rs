@@ -0,0 +1,34 @@
+use crate::components::{Actor, TurnGroup};
+use crate::monsters::{
+    danger_spider_brain, mimic_brain, phase_bat_brain, pungent_ooze_brain, pyro_snake_brain,
+    skeleton_archer_brain, skeleton_scout_brain, volatile_husk_brain,
+};
+use serde::Deserialize;
+
+/// Which of the hand-written brains a monster thinks with
+#[derive(Clone, Copy, Deserialize)]
+pub enum BrainTemplate {
+    DangerSpider,
+    Mimic,
+    PhaseBat,
+    PungentOoze,
+    PyroSnake,
+    SkeletonArcher,
+    SkeletonScout,
+    VolatileHusk,
+}
+
+impl BrainTemplate {
+    pub fn actor(self, turn_group: TurnGroup) -> Actor {
+        match self {
+            BrainTemplate::DangerSpider => Actor::new(danger_spider_brain(), turn_group),
+            BrainTemplate::Mimic => Actor::new(mimic_brain(), turn_group),
+            BrainTemplate::PhaseBat => Actor::new(phase_bat_brain, turn_group),
+            BrainTemplate::PungentOoze => Actor::new(pungent_ooze_brain(), turn_group),
+            BrainTemplate::PyroSnake => Actor::new(pyro_snake_brain(), turn_group),
+            BrainTemplate::SkeletonArcher => Actor::new(skeleton_archer_brain(), turn_group),
+            BrainTemplate::SkeletonScout => Actor::new(skeleton_scout_brain(), turn_group),
+            BrainTemplate::VolatileHusk => Actor::new(volatile_husk_brain(), turn_group),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::actions::{is_webbed, Action, Direction, SpinWebAction, WaitAction};
+use crate::ai::{player_position_if_visible, step_towards};
+use crate::components::{has_status_effect, Brain, GridPosition, StatusEffectKind};
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+
+pub fn danger_spider_brain() -> impl Brain {
+    DangerSpiderBrain::new()
+}
+
+const WEB_COOLDOWN_TURNS: u32 = 3;
+
+/// Wanders between rooms, webbing the corridors it passes through
+/// Lurks when it sees the player, webbing the way between them, and pounces once they're stuck
+#[derive(Clone)]
+struct DangerSpiderBrain {
+    patrol_goal: Option<IVec2>,
+    turns_until_web: u32,
+}
+
+impl DangerSpiderBrain {
+    fn new() -> Self {
+        Self {
+            patrol_goal: None,
+            turns_until_web: 0,
+        }
+    }
+
+    fn spin_web(
+        &mut self,
+        candidates: Vec<IVec2>,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        if self.turns_until_web > 0 {
+            return None;
+        }
+
+        let occupied_positions = world
+            .query::<&GridPosition>()
+            .iter(world)
+            .map(|position| **position)
+            .collect::<HashSet<_>>();
+        let dungeon_layout = world.get_resource::<DungeonLayout>().unwrap();
+        let mut candidates = candidates
+            .into_iter()
+            .filter(|position| dungeon_layout.is_floor(*position))
+            .collect::<Vec<_>>();
+        // Corridors are the best place for a web, nobody can walk around it
+        if candidates
+            .iter()
+            .any(|position| is_corridor(*position, dungeon_layout))
+        {
+            candidates.retain(|position| is_corridor(*position, dungeon_layout));
+        }
+        candidates.retain(|position| !is_webbed(*position, world));
+        let action = candidates
+            .iter()
+            .filter(|position| !occupied_positions.contains(position))
+            .collect::<Vec<_>>()
+            .choose(&mut rand::thread_rng())
+            .and_then(|position| {
+                SpinWebAction {
+                    position: **position,
+                }
+                .to_brain_decision_if_can_perform(world)
+            });
+        if action.is_some() {
+            self.turns_until_web = WEB_COOLDOWN_TURNS;
+        }
+        action
+    }
+
+    fn patrol(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let position = **world.get::<GridPosition>(this_entity)?;
+        if self.patrol_goal.is_none() || self.patrol_goal == Some(position) {
+            self.patrol_goal = world
+                .get_resource::<DungeonLayout>()
+                .unwrap()
+                .room_centers
+                .choose(&mut rand::thread_rng())
+                .copied();
+        }
+
+        let action = step_towards(this_entity, self.patrol_goal?, world);
+        if action.is_none() {
+            self.patrol_goal = None;
+        }
+        action
+    }
+}
+
+impl Brain for DangerSpiderBrain {
+    fn decide_action(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        self.turns_until_web = self.turns_until_web.saturating_sub(1);
+        let position = **world.get::<GridPosition>(this_entity)?;
+
+        let action = if let Some(player_position) = player_position_if_visible(this_entity, world) {
+            let player_stuck = world
+                .player_entity()
+                .map(|player| has_status_effect(world, player, StatusEffectKind::Immobilized))
+                .unwrap_or(false);
+            if player_stuck {
+                step_towards(this_entity, player_position, world)
+            } else {
+                // Web the next tile on the way towards the player
+                let towards_player = world
+                    .get_resource::<DungeonLayout>()
+                    .unwrap()
+                    .find_path(position, player_position, |_| true)
+                    .and_then(|path| path.first().copied())
+                    .filter(|next_position| *next_position != player_position);
+                self.spin_web(towards_player.into_iter().collect(), world)
+            }
+        } else {
+            let mut candidates = vec![position];
+            candidates.extend(
+                Direction::ALL
+                    .iter()
+                    .map(|direction| position + direction.offset()),
+            );
+            self.spin_web(candidates, world)
+                .or_else(|| self.patrol(this_entity, world))
+        };
+
+        action.or_else(|| {
+            WaitAction {
+                entity: this_entity,
+            }
+            .to_brain_decision()
+        })
+    }
+
+    /// Heads for where the player was seen, webbing corridors on the way as usual
+    fn hear_alarm(&mut self, player_position: IVec2) {
+        self.patrol_goal = Some(player_position);
+    }
+}
+
+/// A floor tile with at most two floor neighbors
+fn is_corridor(position: IVec2, dungeon_layout: &DungeonLayout) -> bool {
+    Direction::ALL
+        .iter()
+        .filter(|direction| dungeon_layout.is_floor(position + direction.offset()))
+        .count()
+        <= 2
+}
@@ -0,0 +1,64 @@
+use crate::components::{StatusEffect, TurnGroup};
+use crate::monsters::BrainTemplate;
+use bevy::prelude::Handle;
+use bevy::reflect::TypeUuid;
+use bevy::sprite::ColorMaterial;
+use serde::Deserialize;
+
+/// A kind of monster, loaded from a .monster.ron file under assets/monsters
+#[derive(Clone, Deserialize, TypeUuid)]
+#[uuid = "3777b1c5-b729-4d9e-adc0-c5174c5a17cd"]
+pub struct MonsterDefinition {
+    pub name: String,
+    /// Image file under assets
+    pub sprite: String,
+    pub health: u32,
+    pub attack: u32,
+    pub defense: u32,
+    pub faction: TurnGroup,
+    /// Actions per turn
+    #[serde(default = "default_speed")]
+    pub speed: u32,
+    pub sight_radius: i32,
+    pub brain: BrainTemplate,
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+    /// The shallowest and deepest floors it can appear on
+    pub depth_range: (u32, u32),
+    /// The archetype whose loot table is rolled for what it drops when killed
+    #[serde(default)]
+    pub loot: Option<String>,
+    /// Made from the sprite when loaded
+    #[serde(skip)]
+    pub material: Handle<ColorMaterial>,
+}
+
+fn default_speed() -> u32 {
+    1
+}
+
+/// Extra components given to a monster when it spawns
+#[derive(Clone, Deserialize)]
+pub enum Ability {
+    Phasing,
+    WebWalker,
+    Explosive {
+        radius: i32,
+        damage: u32,
+    },
+    ToxicTrail {
+        puddle_turns: u32,
+    },
+    Splitting {
+        size: u32,
+        min_size: u32,
+        damage_threshold: u32,
+    },
+    /// Applies a status effect to whatever it hits in melee
+    InflictsOnHit(StatusEffect),
+    /// Spawns looking like something else, the sprite being a built-in material
+    Disguised {
+        name: String,
+        sprite: String,
+    },
+}
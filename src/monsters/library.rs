@@ -0,0 +1,71 @@
+use crate::monsters::{MonsterDefinition, SpawnTables};
+use bevy::asset::{AssetEvent, AssetServer, Assets, Handle, LoadState};
+use bevy::log::{info, warn};
+use bevy::prelude::{EventReader, Res};
+
+/// Every monster definition under assets/monsters, and the tables saying where they spawn
+/// Edited files are reloaded while the game runs, affecting monsters spawned afterwards
+/// A missing monsters folder leaves the library empty rather than crashing
+pub struct MonsterLibrary {
+    handles: Vec<Handle<MonsterDefinition>>,
+    spawn_tables: Handle<SpawnTables>,
+}
+
+impl MonsterLibrary {
+    pub fn load(asset_server: &AssetServer) -> Self {
+        Self {
+            handles: match asset_server.load_folder("monsters") {
+                Ok(handles) => handles.into_iter().map(|handle| handle.typed()).collect(),
+                Err(error) => {
+                    warn!("Failed to load monster definitions: {}", error);
+                    Vec::new()
+                }
+            },
+            spawn_tables: asset_server.load("monsters.spawn_tables.ron"),
+        }
+    }
+
+    /// Whether every definition has either loaded or failed to
+    pub fn is_ready(&self, asset_server: &AssetServer) -> bool {
+        !matches!(
+            asset_server.get_group_load_state(
+                self.handles
+                    .iter()
+                    .map(|handle| handle.id)
+                    .chain(Some(self.spawn_tables.id))
+            ),
+            LoadState::NotLoaded | LoadState::Loading
+        )
+    }
+
+    pub fn get<'a>(
+        &self,
+        name: &str,
+        definitions: &'a Assets<MonsterDefinition>,
+    ) -> Option<&'a MonsterDefinition> {
+        self.handles
+            .iter()
+            .filter_map(|handle| definitions.get(handle))
+            .find(|definition| definition.name == name)
+    }
+
+    pub fn spawn_tables<'a>(
+        &self,
+        spawn_tables: &'a Assets<SpawnTables>,
+    ) -> Option<&'a SpawnTables> {
+        spawn_tables.get(&self.spawn_tables)
+    }
+}
+
+pub fn report_reloaded_monster_definitions(
+    mut events: EventReader<AssetEvent<MonsterDefinition>>,
+    definitions: Res<Assets<MonsterDefinition>>,
+) {
+    for event in events.iter() {
+        if let AssetEvent::Modified { handle } = event {
+            if let Some(definition) = definitions.get(handle) {
+                info!("Reloaded monster definition: {}", definition.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::monsters::{MonsterDefinition, SpawnTables};
+use anyhow::anyhow;
+use bevy::asset::{AssetLoader, AssetPath, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::sprite::ColorMaterial;
+use std::path::PathBuf;
+
+#[derive(Default)]
+pub struct MonsterDefinitionLoader;
+
+impl AssetLoader for MonsterDefinitionLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let mut definition = ron::de::from_bytes::<MonsterDefinition>(bytes)?;
+            let (shallowest, deepest) = definition.depth_range;
+            if shallowest > deepest {
+                return Err(anyhow!(
+                    "{} has an empty depth range {:?}",
+                    definition.name,
+                    definition.depth_range
+                ));
+            }
+
+            // The sprite gets its own material, loaded alongside the definition
+            let texture_path = AssetPath::new(PathBuf::from(&definition.sprite), None);
+            let texture = load_context.get_handle(texture_path.clone());
+            definition.material = load_context.set_labeled_asset(
+                "material",
+                LoadedAsset::new(ColorMaterial::texture(texture)).with_dependency(texture_path),
+            );
+            load_context.set_default_asset(LoadedAsset::new(definition));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["monster.ron"]
+    }
+}
+
+#[derive(Default)]
+pub struct SpawnTablesLoader;
+
+impl AssetLoader for SpawnTablesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let spawn_tables = ron::de::from_bytes::<SpawnTables>(bytes)?;
+            if !(0.0..=1.0).contains(&spawn_tables.out_of_depth_chance) {
+                return Err(anyhow!(
+                    "out_of_depth_chance {} isn't between 0 and 1",
+                    spawn_tables.out_of_depth_chance
+                ));
+            }
+            load_context.set_default_asset(LoadedAsset::new(spawn_tables));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["spawn_tables.ron"]
+    }
+}
@@ -0,0 +1,7 @@
+use crate::ai::{approach_player, wait, BehaviorBrain, Selector};
+
+/// Lunges at the player while it can see them, otherwise lies in wait
+/// Alarms are ignored, a mimic wandering off would give its disguise away
+pub fn mimic_brain() -> BehaviorBrain {
+    BehaviorBrain::new(Selector::new().or(approach_player).or(wait)).ignoring_alarms()
+}
@@ -0,0 +1,29 @@
+mod brain_template;
+mod danger_spider;
+mod definition;
+mod library;
+mod loader;
+mod mimic;
+mod phase_bat;
+mod pungent_ooze;
+mod pyro_snake;
+mod skeleton_archer;
+mod skeleton_scout;
+mod spawn;
+mod spawn_tables;
+mod volatile_husk;
+
+pub use brain_template::*;
+pub use danger_spider::*;
+pub use definition::*;
+pub use library::*;
+pub use loader::*;
+pub use mimic::*;
+pub use phase_bat::*;
+pub use pungent_ooze::*;
+pub use pyro_snake::*;
+pub use skeleton_archer::*;
+pub use skeleton_scout::*;
+pub use spawn::*;
+pub use spawn_tables::*;
+pub use volatile_husk::*;
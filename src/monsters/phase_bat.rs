@@ -0,0 +1,45 @@
+use crate::actions::{Action, Direction, WaitAction};
+use crate::ai::{move_or_attack, player_position_if_visible, step_towards};
+use crate::components::{GridPosition, Terrain};
+use crate::world::ImmutableWorld;
+use bevy::prelude::{Entity, With};
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+/// Flutters in a random direction, only sometimes heading for the player
+/// Rarely picks a direction leading into a wall, phasing through it
+/// Ignores alarms, it's too erratic to head anywhere in particular
+pub fn phase_bat_brain(this_entity: Entity, world: &mut ImmutableWorld) -> Option<Box<dyn Action>> {
+    let mut rng = thread_rng();
+
+    if let Some(player_position) = player_position_if_visible(this_entity, world) {
+        if rng.gen_ratio(1, 2) {
+            if let Some(action) = step_towards(this_entity, player_position, world) {
+                return Some(action);
+            }
+        }
+    }
+
+    let position = **world.get::<GridPosition>(this_entity)?;
+    let terrain_positions = world
+        .query_filtered::<&GridPosition, With<Terrain>>()
+        .iter(world)
+        .map(|position| **position)
+        .collect::<Vec<_>>();
+    let mut directions = Direction::ALL;
+    directions.shuffle(&mut rng);
+    for direction in directions {
+        let into_terrain = terrain_positions.contains(&(position + direction.offset()));
+        if into_terrain && !rng.gen_ratio(1, 6) {
+            continue;
+        }
+        if let Some(action) = move_or_attack(this_entity, direction, world) {
+            return Some(action);
+        }
+    }
+
+    WaitAction {
+        entity: this_entity,
+    }
+    .to_brain_decision()
+}
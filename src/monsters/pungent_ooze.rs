@@ -0,0 +1,59 @@
+use crate::actions::{Action, WaitAction};
+use crate::ai::{player_position_if_visible, step_towards};
+use crate::components::Brain;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+
+pub fn pungent_ooze_brain() -> impl Brain {
+    PungentOozeBrain::new()
+}
+
+/// Oozes towards the player, or where an alarm said they were, moving only every other turn
+#[derive(Clone)]
+struct PungentOozeBrain {
+    rested_last_turn: bool,
+    alarm_position: Option<IVec2>,
+}
+
+impl PungentOozeBrain {
+    fn new() -> Self {
+        Self {
+            rested_last_turn: false,
+            alarm_position: None,
+        }
+    }
+}
+
+impl Brain for PungentOozeBrain {
+    fn decide_action(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let wait = WaitAction {
+            entity: this_entity,
+        };
+        self.rested_last_turn = !self.rested_last_turn;
+        if self.rested_last_turn {
+            return wait.to_brain_decision();
+        }
+
+        if let Some(player_position) = player_position_if_visible(this_entity, world) {
+            self.alarm_position = None;
+            return step_towards(this_entity, player_position, world)
+                .or_else(|| wait.to_brain_decision());
+        }
+        let action = self
+            .alarm_position
+            .and_then(|alarm_position| step_towards(this_entity, alarm_position, world));
+        if action.is_none() {
+            self.alarm_position = None;
+        }
+        action.or_else(|| wait.to_brain_decision())
+    }
+
+    fn hear_alarm(&mut self, player_position: IVec2) {
+        self.alarm_position = Some(player_position);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::actions::{Action, Direction, FireBreathAction, MoveAction};
+use crate::ai::{
+    can_see_player, clear_line_direction, flee_from_player, health_below,
+    player_position_if_visible, ranged_attack_if_aligned, wait, BehaviorBrain, Cooldown, Selector,
+    Utility, When,
+};
+use crate::components::GridPosition;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+
+const BREATH_RANGE: i32 = 5;
+const BREATH_DAMAGE: u32 = 3;
+const BREATH_RECHARGE_TURNS: u32 = 5;
+const PREFERRED_DISTANCE: i32 = 3;
+const FLEE_BELOW_HEALTH_PERCENT: u32 = 30;
+
+/// Flees once badly hurt
+/// Otherwise breathes fire at the player whenever they line up and the breath has recharged
+/// Otherwise slithers to keep its distance, preferring tiles in line with the player
+pub fn pyro_snake_brain() -> BehaviorBrain {
+    let breathe_fire = |entity, direction| -> Box<dyn Action> {
+        Box::new(FireBreathAction {
+            entity,
+            direction,
+            range: BREATH_RANGE,
+            damage: BREATH_DAMAGE,
+        })
+    };
+
+    let mut keep_distance = Utility::new().option(
+        |this_entity: Entity, world: &mut ImmutableWorld| {
+            let position = **world.get::<GridPosition>(this_entity)?;
+            score_position(this_entity, position, world)
+        },
+        wait,
+    );
+    for direction in Direction::ALL {
+        keep_distance = keep_distance.option(
+            move |this_entity: Entity, world: &mut ImmutableWorld| {
+                let move_action = MoveAction {
+                    entity: this_entity,
+                    direction,
+                };
+                if !move_action.can_perform(world) {
+                    return None;
+                }
+                let position = **world.get::<GridPosition>(this_entity)?;
+                score_position(this_entity, position + direction.offset(), world)
+            },
+            move |this_entity: Entity, world: &mut ImmutableWorld| {
+                MoveAction {
+                    entity: this_entity,
+                    direction,
+                }
+                .to_brain_decision_if_can_perform(world)
+            },
+        );
+    }
+
+    BehaviorBrain::new(
+        Selector::new()
+            .or(When::new(
+                health_below(FLEE_BELOW_HEALTH_PERCENT),
+                flee_from_player,
+            ))
+            .or(Cooldown::new(
+                BREATH_RECHARGE_TURNS,
+                ranged_attack_if_aligned(BREATH_RANGE, breathe_fire),
+            ))
+            .or(When::new(can_see_player, keep_distance))
+            .or(wait),
+    )
+}
+
+fn score_position(this_entity: Entity, position: IVec2, world: &mut ImmutableWorld) -> Option<i32> {
+    let player_position = player_position_if_visible(this_entity, world)?;
+    let offset = player_position - position;
+    let distance = offset.x.abs() + offset.y.abs();
+    let mut score = -(distance - PREFERRED_DISTANCE).abs() * 2;
+    if clear_line_direction(position, player_position, BREATH_RANGE, world).is_some() {
+        score += 1;
+    }
+    Some(score)
+}
@@ -0,0 +1,23 @@
+use crate::ai::{
+    approach_player, can_see_player, flee_from_player, player_adjacent,
+    shoot_player_if_in_line_of_fire, wait, BehaviorBrain, Cooldown, Selector, When,
+};
+
+const ARROW_RANGE: i32 = 6;
+const ARROW_DAMAGE: u32 = 2;
+const RELOAD_TURNS: u32 = 2;
+
+/// Backs away from the player when they get close
+/// Otherwise shoots whenever it has a clear shot and has reloaded, closing in when it doesn't
+pub fn skeleton_archer_brain() -> BehaviorBrain {
+    BehaviorBrain::new(
+        Selector::new()
+            .or(When::new(player_adjacent, flee_from_player))
+            .or(Cooldown::new(
+                RELOAD_TURNS,
+                shoot_player_if_in_line_of_fire(ARROW_RANGE, ARROW_DAMAGE),
+            ))
+            .or(When::new(can_see_player, approach_player))
+            .or(wait),
+    )
+}
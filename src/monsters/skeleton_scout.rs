@@ -0,0 +1,102 @@
+use crate::actions::{Action, RaiseAlarmAction, WaitAction};
+use crate::ai::{player_position_if_visible, step_towards};
+use crate::components::{Brain, GridPosition};
+use crate::dungeon::DungeonLayout;
+use crate::world::ImmutableWorld;
+use bevy::math::IVec2;
+use bevy::prelude::Entity;
+use rand::seq::SliceRandom;
+
+pub fn skeleton_scout_brain() -> impl Brain {
+    SkeletonScoutBrain::new()
+}
+
+const ALARM_RADIUS: i32 = 10;
+const ALARM_COOLDOWN_TURNS: u32 = 12;
+
+/// Patrols between rooms until it spots the player
+/// On spotting the player it raises the alarm, then gives chase
+/// When it loses sight of the player it searches their last known position
+#[derive(Clone)]
+struct SkeletonScoutBrain {
+    patrol_goal: Option<IVec2>,
+    last_known_player_position: Option<IVec2>,
+    turns_until_alarm: u32,
+}
+
+impl SkeletonScoutBrain {
+    fn new() -> Self {
+        Self {
+            patrol_goal: None,
+            last_known_player_position: None,
+            turns_until_alarm: 0,
+        }
+    }
+
+    fn patrol(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        let position = **world.get::<GridPosition>(this_entity)?;
+        if self.patrol_goal.is_none() || self.patrol_goal == Some(position) {
+            self.patrol_goal = world
+                .get_resource::<DungeonLayout>()
+                .unwrap()
+                .room_centers
+                .choose(&mut rand::thread_rng())
+                .copied();
+        }
+
+        let action = step_towards(this_entity, self.patrol_goal?, world);
+        if action.is_none() {
+            // Blocked, try a different room next turn
+            self.patrol_goal = None;
+        }
+        action
+    }
+}
+
+impl Brain for SkeletonScoutBrain {
+    fn decide_action(
+        &mut self,
+        this_entity: Entity,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        self.turns_until_alarm = self.turns_until_alarm.saturating_sub(1);
+
+        let action = if let Some(player_position) = player_position_if_visible(this_entity, world) {
+            self.last_known_player_position = Some(player_position);
+            if self.turns_until_alarm == 0 {
+                self.turns_until_alarm = ALARM_COOLDOWN_TURNS;
+                RaiseAlarmAction {
+                    entity: this_entity,
+                    player_position,
+                    radius: ALARM_RADIUS,
+                }
+                .to_brain_decision_if_can_perform(world)
+            } else {
+                step_towards(this_entity, player_position, world)
+            }
+        } else if let Some(last_known_player_position) = self.last_known_player_position {
+            let action = step_towards(this_entity, last_known_player_position, world);
+            if action.is_none() {
+                self.last_known_player_position = None;
+            }
+            action
+        } else {
+            self.patrol(this_entity, world)
+        };
+
+        action.or_else(|| {
+            WaitAction {
+                entity: this_entity,
+            }
+            .to_brain_decision()
+        })
+    }
+
+    fn hear_alarm(&mut self, player_position: IVec2) {
+        self.last_known_player_position = Some(player_position);
+    }
+}
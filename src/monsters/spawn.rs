@@ -0,0 +1,56 @@
+use crate::bundles::{MaterialMap, Monster};
+use crate::components::{
+    Actor, Disguise, DropsLoot, Explosive, InflictsOnHit, Phasing, Splitting, ToxicTrail, WebWalker,
+};
+use crate::monsters::{Ability, MonsterDefinition};
+use bevy::core::Name;
+use bevy::math::IVec2;
+use bevy::prelude::{Entity, World};
+
+/// Spawns a monster along with the components for its abilities and loot
+pub fn spawn_monster(world: &mut World, definition: &MonsterDefinition, position: IVec2) -> Entity {
+    let mut monster = world.spawn();
+    monster.insert_bundle(Monster::new(definition, position.x, position.y));
+    if let Some(archetype) = &definition.loot {
+        monster.insert(DropsLoot(archetype.clone()));
+    }
+    for ability in definition.abilities.iter().cloned() {
+        match ability {
+            Ability::Phasing => {
+                monster.insert(Phasing);
+            }
+            Ability::WebWalker => {
+                monster.insert(WebWalker);
+            }
+            Ability::Explosive { radius, damage } => {
+                monster.insert(Explosive::new(radius, damage));
+            }
+            Ability::ToxicTrail { puddle_turns } => {
+                monster.insert(ToxicTrail::new(puddle_turns));
+            }
+            Ability::Splitting {
+                size,
+                min_size,
+                damage_threshold,
+            } => {
+                monster.insert(Splitting::new(size, min_size, damage_threshold));
+            }
+            Ability::InflictsOnHit(effect) => {
+                monster.insert(InflictsOnHit(effect));
+            }
+            Ability::Disguised { name, sprite } => {
+                // Doesn't act until revealed
+                let actor = monster.remove::<Actor>().unwrap();
+                monster
+                    .insert(Name::new(name))
+                    .insert(MaterialMap::get(&sprite))
+                    .insert(Disguise::new(
+                        definition.name.clone(),
+                        definition.material.clone(),
+                        actor,
+                    ));
+            }
+        }
+    }
+    monster.id()
+}
@@ -0,0 +1,132 @@
+use bevy::reflect::TypeUuid;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::Deserialize;
+
+/// Which monsters show up on which floors, loaded from assets/monsters.spawn_tables.ron
+#[derive(Clone, Deserialize, TypeUuid)]
+#[uuid = "5d0c3f0e-8a43-4b8e-9a57-2f4c7c1f9b61"]
+pub struct SpawnTables {
+    /// How many groups each room gets, other than the starting room
+    pub groups_per_room: (u32, u32),
+    /// Chance of a group being rolled as if it were on a deeper floor
+    pub out_of_depth_chance: f64,
+    /// The most floors deeper an out of depth group can come from
+    pub max_out_of_depth: u32,
+    pub tables: Vec<SpawnTable>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct SpawnTable {
+    /// The shallowest and deepest floors the table is used on
+    pub depths: (u32, u32),
+    pub entries: Vec<SpawnEntry>,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct SpawnEntry {
+    pub monster: String,
+    pub weight: u32,
+    pub group_size: (u32, u32),
+}
+
+impl SpawnTables {
+    /// Picks a monster and how many of it to spawn together
+    /// Floors deeper than every table are rolled as the deepest floor of the deepest one
+    /// can_appear filters out monsters that shouldn't be on the (possibly out of depth) floor
+    pub fn roll_group<F, R>(&self, depth: u32, can_appear: F, rng: &mut R) -> Option<(String, u32)>
+    where
+        F: Fn(&str, u32) -> bool,
+        R: Rng,
+    {
+        let mut depth = depth;
+        if self.max_out_of_depth > 0 && rng.gen_bool(self.out_of_depth_chance) {
+            depth += rng.gen_range(1..=self.max_out_of_depth);
+        }
+        let deepest = self.tables.iter().map(|table| table.depths.1).max()?;
+        let depth = depth.min(deepest);
+
+        let entries = self
+            .tables
+            .iter()
+            .filter(|table| (table.depths.0..=table.depths.1).contains(&depth))
+            .flat_map(|table| table.entries.iter())
+            .filter(|entry| can_appear(&entry.monster, depth))
+            .collect::<Vec<_>>();
+
+        let entry = entries.choose_weighted(rng, |entry| entry.weight).ok()?;
+        let (smallest, largest) = entry.group_size;
+        Some((
+            entry.monster.clone(),
+            rng.gen_range(smallest..=largest.max(smallest)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn spawn_tables() -> SpawnTables {
+        SpawnTables {
+            groups_per_room: (1, 1),
+            out_of_depth_chance: 0.0,
+            max_out_of_depth: 0,
+            tables: vec![
+                SpawnTable {
+                    depths: (1, 3),
+                    entries: vec![SpawnEntry {
+                        monster: "Rat".to_owned(),
+                        weight: 1,
+                        group_size: (2, 3),
+                    }],
+                },
+                SpawnTable {
+                    depths: (4, 12),
+                    entries: vec![SpawnEntry {
+                        monster: "Skeleton".to_owned(),
+                        weight: 1,
+                        group_size: (1, 1),
+                    }],
+                },
+            ],
+        }
+    }
+
+    fn can_appear(name: &str, depth: u32) -> bool {
+        match name {
+            "Rat" => (1..=3).contains(&depth),
+            "Skeleton" => (4..=12).contains(&depth),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn rolls_from_the_table_for_the_depth() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let (monster, group_size) = spawn_tables().roll_group(2, can_appear, &mut rng).unwrap();
+        assert_eq!(monster, "Rat");
+        assert!((2..=3).contains(&group_size));
+    }
+
+    #[test]
+    fn floors_past_the_deepest_table_still_get_monsters() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for depth in [13, 20, 100] {
+            let group = spawn_tables().roll_group(depth, can_appear, &mut rng);
+            assert_eq!(group, Some(("Skeleton".to_owned(), 1)));
+        }
+    }
+
+    #[test]
+    fn out_of_depth_groups_past_the_deepest_table_still_get_monsters() {
+        let mut spawn_tables = spawn_tables();
+        spawn_tables.out_of_depth_chance = 1.0;
+        spawn_tables.max_out_of_depth = 5;
+        let mut rng = StdRng::seed_from_u64(0);
+        let group = spawn_tables.roll_group(12, can_appear, &mut rng);
+        assert_eq!(group, Some(("Skeleton".to_owned(), 1)));
+    }
+}
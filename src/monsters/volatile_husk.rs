@@ -0,0 +1,25 @@
+use crate::actions::{Action, DeathAction};
+use crate::ai::{approach_player, player_adjacent, wait, BehaviorBrain, Selector, Sequence, When};
+use crate::world::ImmutableWorld;
+use bevy::prelude::Entity;
+
+/// Shambles towards the player, moving only every other turn
+/// Detonates itself if it's still next to the player a turn after reaching them
+pub fn volatile_husk_brain() -> BehaviorBrain {
+    // Dying sets off the explosion
+    let detonate = |this_entity: Entity, _: &mut ImmutableWorld| {
+        DeathAction {
+            entity: this_entity,
+        }
+        .to_brain_decision()
+    };
+
+    BehaviorBrain::new(
+        Selector::new()
+            .or(Sequence::new()
+                .then(When::new(player_adjacent, wait))
+                .then(When::new(player_adjacent, detonate)))
+            .or(Sequence::new().then(wait).then(approach_player))
+            .or(wait),
+    )
+}
@@ -0,0 +1,128 @@
+use crate::components::{
+    Actor, Equipment, FloorItem, GridPosition, Inventory, TileEffect, TurnGroup, Viewshed,
+};
+use crate::dungeon::DungeonLayout;
+use crate::input::CursorTile;
+use crate::items::ItemAppearances;
+use crate::ui::{describe_equipment, describe_inventory};
+use bevy::core::Name;
+use bevy::prelude::{Query, Res, ResMut, Transform, With};
+use bevy::render::draw::Visible;
+use bevy::window::Windows;
+
+pub const WINDOW_TITLE: &str = "Dungeon Heart";
+
+pub struct HoverHighlight;
+
+pub fn highlight_hovered_tile(
+    cursor_tile: Res<CursorTile>,
+    mut highlight: Query<(&mut Transform, &mut Visible), With<HoverHighlight>>,
+) {
+    if let Ok((mut transform, mut visible)) = highlight.single_mut() {
+        match cursor_tile.hovered {
+            Some(hovered) => {
+                transform.translation.x = (hovered.x * 32) as f32;
+                transform.translation.y = (hovered.y * 32) as f32;
+                visible.is_visible = true;
+            }
+            None => visible.is_visible = false,
+        }
+    }
+}
+
+type Viewer<'a> = (
+    &'a Viewshed,
+    &'a Actor,
+    &'a GridPosition,
+    Option<&'a Inventory>,
+    Option<&'a Equipment>,
+);
+
+/// Describes what occupies the hovered tile in the window title
+/// Tiles out of view are described from memory, unexplored tiles not at all
+/// Hovering the player, or nothing at all as with a gamepad, lists their equipment and inventory
+/// Once the player has died the title only says so
+pub fn inspect_hovered_tile(
+    cursor_tile: Res<CursorTile>,
+    dungeon_layout: Res<DungeonLayout>,
+    appearances: Res<ItemAppearances>,
+    viewers: Query<Viewer>,
+    occupants: Query<(&GridPosition, &Name)>,
+    lying_on_floor: Query<(Option<&TileEffect>, Option<&FloorItem>, Option<&Name>)>,
+    mut windows: ResMut<Windows>,
+) {
+    let description = cursor_tile.hovered.map(|hovered| {
+        let player_can_see = viewers
+            .iter()
+            .filter(|(_, actor, _, _, _)| actor.turn_group == TurnGroup::Player)
+            .any(|(viewshed, _, _, _, _)| viewshed.can_see(hovered));
+        if player_can_see {
+            let names =
+                occupants
+                    .iter()
+                    .filter(|(position, _)| ***position == hovered)
+                    .map(|(_, name)| name.to_string())
+                    .chain(lying_on_floor.iter().filter_map(
+                        |(tile_effect, floor_item, name)| match (tile_effect, floor_item, name) {
+                            (Some(tile_effect), _, Some(name))
+                                if tile_effect.position == hovered =>
+                            {
+                                Some(name.to_string())
+                            }
+                            (_, Some(floor_item), _) if floor_item.position == hovered => {
+                                Some(appearances.describe(&floor_item.stack))
+                            }
+                            _ => None,
+                        },
+                    ))
+                    .collect::<Vec<_>>();
+            if !names.is_empty() {
+                names.join(", ")
+            } else if dungeon_layout.is_floor(hovered) {
+                "Floor".to_owned()
+            } else {
+                "Nothing".to_owned()
+            }
+        } else if dungeon_layout.is_explored(hovered) {
+            if dungeon_layout.wall_positions.contains(&hovered) {
+                "Wall (remembered)".to_owned()
+            } else {
+                "Floor (remembered)".to_owned()
+            }
+        } else {
+            "Unexplored".to_owned()
+        }
+    });
+
+    let player = viewers
+        .iter()
+        .find(|(_, actor, _, _, _)| actor.turn_group == TurnGroup::Player);
+    let description = match player {
+        Some(_) => description,
+        None => Some("You died".to_owned()),
+    };
+    let mut title = match description {
+        Some(description) => format!("{} - {}", WINDOW_TITLE, description),
+        None => WINDOW_TITLE.to_owned(),
+    };
+    let player = player.filter(|(_, _, position, _, _)| {
+        cursor_tile.hovered.is_none() || cursor_tile.hovered == Some(***position)
+    });
+    if let Some((_, _, _, inventory, equipment)) = player {
+        if let Some(description) = equipment.and_then(describe_equipment) {
+            title = format!("{} | {}", title, description);
+        }
+        if let Some(inventory) = inventory {
+            title = format!(
+                "{} | {}",
+                title,
+                describe_inventory(inventory, &appearances)
+            );
+        }
+    }
+    if let Some(window) = windows.get_primary_mut() {
+        if window.title() != title {
+            window.set_title(title);
+        }
+    }
+}
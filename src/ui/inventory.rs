@@ -0,0 +1,56 @@
+use crate::components::{Equipment, Inventory};
+use crate::input::PlayerCommand;
+use crate::items::{EquipmentSlot, ItemAppearances};
+use bevy::input::Input;
+use bevy::prelude::{Query, Res};
+
+/// Cycles which stack the player's item commands act on
+pub fn select_next_item(
+    player_commands: Res<Input<PlayerCommand>>,
+    mut inventories: Query<&mut Inventory>,
+) {
+    if player_commands.just_pressed(PlayerCommand::NextItem) {
+        for mut inventory in inventories.iter_mut() {
+            inventory.select_next();
+        }
+    }
+}
+
+/// Lists the carried stacks, with the selected one in brackets
+pub fn describe_inventory(inventory: &Inventory, appearances: &ItemAppearances) -> String {
+    if inventory.stacks.is_empty() {
+        return "Carrying nothing".to_owned();
+    }
+    let stacks = inventory
+        .stacks
+        .iter()
+        .enumerate()
+        .map(|(index, stack)| {
+            if index == inventory.selected {
+                format!("[{}]", appearances.describe(stack))
+            } else {
+                appearances.describe(stack)
+            }
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "Carrying {}/{}: {}",
+        inventory.stacks.len(),
+        inventory.capacity,
+        stacks.join(", ")
+    )
+}
+
+/// Lists what's worn in each slot, or None if nothing is
+pub fn describe_equipment(equipment: &Equipment) -> Option<String> {
+    let worn = EquipmentSlot::ALL
+        .iter()
+        .filter_map(|slot| equipment.get(*slot))
+        .map(|item| item.display_name())
+        .collect::<Vec<_>>();
+    if worn.is_empty() {
+        None
+    } else {
+        Some(format!("Wearing {}", worn.join(", ")))
+    }
+}
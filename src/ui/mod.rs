@@ -0,0 +1,7 @@
+mod hover_inspection;
+mod inventory;
+mod targeting;
+
+pub use hover_inspection::*;
+pub use inventory::*;
+pub use targeting::*;
@@ -0,0 +1,314 @@
+use crate::actions::{
+    Action, ActionStack, Direction, LineOfFire, ShootAction, ThrowAction, UseItemAction,
+    FIREBALL_RADIUS, FIREBALL_RANGE, SHATTER_RADIUS, THROW_RANGE,
+};
+use crate::bundles::SpriteBundleExt;
+use crate::components::{
+    derived_combat_stats, Actor, GridPosition, Inventory, TurnGroup, Viewshed,
+};
+use crate::dungeon::DungeonLayout;
+use crate::input::{CursorTile, PlayerCommand};
+use crate::items::{ConsumableEffect, Item, ItemAppearances, ItemKind};
+use crate::world::ImmutableWorld;
+use bevy::input::Input;
+use bevy::math::IVec2;
+use bevy::prelude::{Commands, Entity, Query, Res, ResMut, SpriteBundle, With};
+use std::sync::Arc;
+
+const SHOT_RANGE: i32 = 6;
+
+type AimedAction =
+    dyn Fn(Entity, IVec2, &mut ImmutableWorld) -> Option<Box<dyn Action>> + Send + Sync;
+
+/// How far an aimed ability reaches, what it covers, and the action it becomes once aimed
+#[derive(Clone)]
+pub struct Aim {
+    pub range: i32,
+    /// How far around the impact tile the ability also reaches, 0 for just that tile
+    pub radius: i32,
+    action: Arc<AimedAction>,
+}
+
+impl Aim {
+    pub fn new<F>(range: i32, radius: i32, action: F) -> Self
+    where
+        F: Fn(Entity, IVec2, &mut ImmutableWorld) -> Option<Box<dyn Action>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            range,
+            radius,
+            action: Arc::new(action),
+        }
+    }
+
+    /// Fires a projectile that deals the shooter's attack in damage
+    pub fn shot() -> Self {
+        Self::new(SHOT_RANGE, 0, |shooter, target, world| {
+            let damage = derived_combat_stats(world, shooter)?.attack;
+            ShootAction {
+                shooter,
+                target,
+                range: SHOT_RANGE,
+                damage,
+            }
+            .to_brain_decision_if_can_perform(world)
+        })
+    }
+
+    /// Uses the consumable at the index of the user's inventory, for the ones that need a target
+    pub fn consumable(effect: ConsumableEffect, index: usize) -> Self {
+        let (range, radius) = match effect {
+            ConsumableEffect::Fireball => (FIREBALL_RANGE, FIREBALL_RADIUS),
+            _ => (0, 0),
+        };
+        Self::new(range, radius, move |user, target, world| {
+            UseItemAction {
+                entity: user,
+                index,
+                target: Some(target),
+            }
+            .to_brain_decision_if_can_perform(world)
+        })
+    }
+
+    /// Throws one of the item at the index of the thrower's inventory, which shatters if it's a potion
+    pub fn throw(item: &Item, index: usize) -> Self {
+        let radius = match item.kind {
+            ItemKind::Consumable(effect) if effect.is_potion() => SHATTER_RADIUS,
+            _ => 0,
+        };
+        Self::new(THROW_RANGE, radius, move |thrower, target, world| {
+            ThrowAction {
+                thrower,
+                index,
+                target,
+            }
+            .to_brain_decision_if_can_perform(world)
+        })
+    }
+
+    pub fn action(
+        &self,
+        aimer: Entity,
+        target: IVec2,
+        world: &mut ImmutableWorld,
+    ) -> Option<Box<dyn Action>> {
+        (self.action)(aimer, target, world)
+    }
+}
+
+/// The player choosing a tile for an aimed ability
+pub struct Targeting {
+    /// None outside of targeting mode
+    pub aim: Option<Aim>,
+    pub cursor: IVec2,
+    /// Set when a target is confirmed, which also ends targeting mode
+    /// Kept until the player's brain next decides, see decide_next_action
+    pub confirmed: Option<(Aim, IVec2)>,
+    last_hovered: Option<IVec2>,
+}
+
+impl Targeting {
+    pub fn new() -> Self {
+        Self {
+            aim: None,
+            cursor: IVec2::ZERO,
+            confirmed: None,
+            last_hovered: None,
+        }
+    }
+}
+
+/// Starts targeting a shot when Fire is pressed on the player's turn,
+/// a throw of the selected item when Throw is pressed,
+/// or the selected consumable when Use is pressed and it needs a target
+/// The cursor starts on the closest visible enemy, or the player if there are none
+/// While targeting, the cursor follows the mouse and moves with the movement commands,
+/// and the next target command cycles through visible enemies from closest to furthest
+/// Confirming or clicking a tile ends targeting with that tile, cancelling ends it without one
+pub fn update_targeting(
+    player_commands: Res<Input<PlayerCommand>>,
+    mut cursor_tile: ResMut<CursorTile>,
+    turn_group: Res<TurnGroup>,
+    action_stack: Res<ActionStack>,
+    appearances: Res<ItemAppearances>,
+    actors: Query<(&Actor, &GridPosition, Option<&Viewshed>, Option<&Inventory>)>,
+    mut targeting: ResMut<Targeting>,
+) {
+    let player = actors
+        .iter()
+        .find(|(actor, _, _, _)| actor.turn_group == TurnGroup::Player);
+    let (player_position, viewshed, inventory) = match player {
+        Some((_, position, Some(viewshed), inventory)) => (**position, viewshed, inventory),
+        _ => return,
+    };
+    let players_turn = *turn_group == TurnGroup::Player && action_stack.is_empty();
+    if !players_turn {
+        if targeting.aim.is_some() {
+            targeting.aim = None;
+        }
+        return;
+    }
+
+    let mut enemy_positions = actors
+        .iter()
+        .filter(|(actor, position, _, _)| {
+            actor.turn_group == TurnGroup::Enemy && viewshed.can_see(***position)
+        })
+        .map(|(_, position, _, _)| **position)
+        .collect::<Vec<_>>();
+    enemy_positions.sort_by_key(|position| {
+        let offset = *position - player_position;
+        offset.x.abs() + offset.y.abs()
+    });
+
+    let aim = match targeting.aim.clone() {
+        Some(aim) => aim,
+        None => {
+            let selected = inventory.and_then(|inventory| {
+                let stack = inventory.stacks.get(inventory.selected)?;
+                Some((&stack.item, inventory.selected))
+            });
+            // Aiming an unknown consumable would give away what it is
+            let aimed_consumable = selected.and_then(|(item, index)| match item.kind {
+                ItemKind::Consumable(effect)
+                    if effect.needs_target() && appearances.is_identified(effect) =>
+                {
+                    Some(Aim::consumable(effect, index))
+                }
+                _ => None,
+            });
+            let aim = if player_commands.just_pressed(PlayerCommand::Fire) {
+                Some(Aim::shot())
+            } else if player_commands.just_pressed(PlayerCommand::Throw) {
+                selected.map(|(item, index)| Aim::throw(item, index))
+            } else if player_commands.just_pressed(PlayerCommand::Use) {
+                aimed_consumable
+            } else {
+                None
+            };
+            if let Some(aim) = aim {
+                targeting.aim = Some(aim);
+                targeting.cursor = enemy_positions.first().copied().unwrap_or(player_position);
+                targeting.last_hovered = cursor_tile.hovered;
+            }
+            return;
+        }
+    };
+
+    if player_commands.just_pressed(PlayerCommand::Cancel) {
+        targeting.aim = None;
+        return;
+    }
+
+    if cursor_tile.hovered != targeting.last_hovered {
+        targeting.last_hovered = cursor_tile.hovered;
+        if let Some(hovered) = cursor_tile.hovered {
+            targeting.cursor = hovered;
+        }
+    }
+    for direction in Direction::ALL {
+        if player_commands.just_pressed(PlayerCommand::Move(direction)) {
+            targeting.cursor += direction.offset();
+        }
+    }
+    if player_commands.just_pressed(PlayerCommand::NextTarget) && !enemy_positions.is_empty() {
+        let next = enemy_positions
+            .iter()
+            .position(|position| *position == targeting.cursor)
+            .map(|index| (index + 1) % enemy_positions.len())
+            .unwrap_or(0);
+        targeting.cursor = enemy_positions[next];
+    }
+
+    let clicked = cursor_tile.clicked.take();
+    if let Some(clicked) = clicked {
+        targeting.cursor = clicked;
+    }
+    let confirm_pressed = player_commands.just_pressed(PlayerCommand::Confirm)
+        || player_commands.just_pressed(PlayerCommand::Fire)
+        || player_commands.just_pressed(PlayerCommand::Throw)
+        || clicked.is_some();
+    if confirm_pressed && targeting.cursor != player_position {
+        targeting.confirmed = Some((aim, targeting.cursor));
+        targeting.aim = None;
+    }
+}
+
+pub struct TargetingHighlight;
+
+/// Highlights the path to the cursor, stopping where a projectile would,
+/// and the area the aimed ability would cover from there
+pub fn highlight_targeting(
+    mut commands: Commands,
+    targeting: Res<Targeting>,
+    dungeon_layout: Res<DungeonLayout>,
+    actors: Query<(&Actor, &GridPosition)>,
+    occupants: Query<(&GridPosition, Entity)>,
+    highlights: Query<Entity, With<TargetingHighlight>>,
+) {
+    if !targeting.is_changed() {
+        return;
+    }
+    for highlight in highlights.iter() {
+        commands.entity(highlight).despawn();
+    }
+
+    let aim = match &targeting.aim {
+        Some(aim) => aim,
+        None => return,
+    };
+    let player_position = match actors
+        .iter()
+        .find(|(actor, _)| actor.turn_group == TurnGroup::Player)
+    {
+        Some((_, position)) => **position,
+        None => return,
+    };
+
+    let line_of_fire = LineOfFire::trace_with(
+        player_position,
+        targeting.cursor,
+        aim.range,
+        &dungeon_layout,
+        |position| {
+            occupants
+                .iter()
+                .find(|(occupant_position, _)| ***occupant_position == position)
+                .map(|(_, entity)| entity)
+        },
+    );
+    let impact = match line_of_fire.path.last() {
+        Some(impact) => *impact,
+        None => return,
+    };
+    let area = if aim.radius > 0 {
+        let burst_position = line_of_fire.burst_position(player_position, &dungeon_layout);
+        dungeon_layout.visible_positions(burst_position, aim.radius)
+    } else {
+        [impact].iter().copied().collect()
+    };
+
+    let mut spawn_highlight = |material: &str, position: IVec2| {
+        let mut sprite = SpriteBundle::new_overlay(material, position.x, position.y);
+        sprite.transform.translation.z = 2.0;
+        commands.spawn_bundle(sprite).insert(TargetingHighlight);
+    };
+    for position in line_of_fire
+        .path
+        .iter()
+        .copied()
+        .filter(|position| !area.contains(position))
+    {
+        spawn_highlight("targeting_path", position);
+    }
+    for position in area {
+        spawn_highlight("targeting_area", position);
+    }
+    if targeting.cursor != impact {
+        spawn_highlight("tile_highlight", targeting.cursor);
+    }
+}
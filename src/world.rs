@@ -1,8 +1,9 @@
 use crate::actions::{Action, ActionStack};
+use crate::components::{Actor, TurnGroup};
 use bevy::ecs::prelude::QueryState;
-use bevy::ecs::query::{ReadOnlyFetch, WorldQuery};
+use bevy::ecs::query::{FilterFetch, ReadOnlyFetch, WorldQuery};
 use bevy::math::{Rect, Vec2};
-use bevy::prelude::{GlobalTransform, World};
+use bevy::prelude::{Entity, GlobalTransform, World};
 use bevy::render::camera::OrthographicProjection;
 use std::ops::Deref;
 
@@ -22,6 +23,24 @@ impl<'a> ImmutableWorld<'a> {
     {
         self.world.query()
     }
+
+    pub fn query_filtered<Q, F>(&mut self) -> QueryState<Q, F>
+    where
+        Q: WorldQuery,
+        F: WorldQuery,
+        <Q as WorldQuery>::Fetch: ReadOnlyFetch,
+        <F as WorldQuery>::Fetch: FilterFetch,
+    {
+        self.world.query_filtered()
+    }
+
+    pub fn player_entity(&mut self) -> Option<Entity> {
+        self.world
+            .query::<(&Actor, Entity)>()
+            .iter(self.world)
+            .find(|(actor, _)| actor.turn_group == TurnGroup::Player)
+            .map(|(_, entity)| entity)
+    }
 }
 
 impl Deref for ImmutableWorld<'_> {